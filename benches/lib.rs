@@ -17,6 +17,6 @@ fn forward_haar(size: usize, bencher: &mut Bencher) {
     let mut data = vec![42.0; size];
     let operation = Operation::Forward;
     let wavelet = wavelet::Haar::new();
-    let level = (size as f64).log2() as usize;
+    let level = dwt::max_level(size, &wavelet);
     bencher.iter(|| black_box(data.transform(operation, &wavelet, level)));
 }
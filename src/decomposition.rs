@@ -0,0 +1,161 @@
+use num::Float;
+
+use {Mode, Result};
+use extension::{sizes, try_decompose, try_reconstruct};
+use wavelet::Wavelet;
+
+/// A multilevel decomposition.
+///
+/// The levels are numbered from one, which is the shallowest level, that is,
+/// the one with the finest details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decomposition<T> {
+    coefficients: Vec<T>,
+    offsets: Vec<usize>,
+    mode: Mode,
+    length: usize,
+}
+
+/// An iterator over the detail coefficients of a decomposition.
+///
+/// The items are pairs of a level and the corresponding coefficients, and they
+/// go from the deepest level to the shallowest one.
+pub struct Details<'l, T: 'l> {
+    decomposition: &'l Decomposition<T>,
+    level: usize,
+}
+
+/// Decompose a signal into a structured decomposition.
+///
+/// The function is a counterpart of `decompose` and panics if the
+/// decomposition is not possible; see `try_wavedec`.
+pub fn wavedec<T>(data: &[T], wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Decomposition<T>
+    where T: Float
+{
+    try_wavedec(data, wavelet, level, mode).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Reconstruct a signal from a structured decomposition.
+///
+/// The wavelet should be the one used for the decomposition. The function is a
+/// counterpart of `reconstruct` and panics if the reconstruction is not
+/// possible; see `try_waverec`.
+pub fn waverec<T>(decomposition: &Decomposition<T>, wavelet: &Wavelet<T>) -> Vec<T>
+    where T: Float
+{
+    try_waverec(decomposition, wavelet).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Decompose a signal into a structured decomposition if possible.
+pub fn try_wavedec<T>(data: &[T], wavelet: &Wavelet<T>, level: usize,
+                      mode: Mode) -> Result<Decomposition<T>>
+    where T: Float
+{
+    let coefficients = try_decompose(data, wavelet, level, mode)?;
+    let offsets = sizes(wavelet, data.len(), level, mode).iter().fold(vec![0], |mut offsets, size| {
+        let offset = offsets[offsets.len() - 1] + size;
+        offsets.push(offset);
+        offsets
+    });
+    debug_assert_eq!(offsets[offsets.len() - 1], coefficients.len());
+    Ok(Decomposition { coefficients, offsets, mode, length: data.len() })
+}
+
+/// Reconstruct a signal from a structured decomposition if possible.
+pub fn try_waverec<T>(decomposition: &Decomposition<T>, wavelet: &Wavelet<T>) -> Result<Vec<T>>
+    where T: Float
+{
+    try_reconstruct(&decomposition.coefficients, wavelet, decomposition.level(),
+                    decomposition.mode, decomposition.length)
+}
+
+impl<T> Decomposition<T> {
+    /// Return the approximation coefficients at the deepest level.
+    #[inline]
+    pub fn approximation(&self) -> &[T] {
+        &self.coefficients[self.offsets[0]..self.offsets[1]]
+    }
+
+    /// Return the approximation coefficients at the deepest level for
+    /// modification.
+    #[inline]
+    pub fn approximation_mut(&mut self) -> &mut [T] {
+        &mut self.coefficients[self.offsets[0]..self.offsets[1]]
+    }
+
+    /// Return the detail coefficients at a level.
+    ///
+    /// The function panics if the level is not between one and the number of
+    /// levels.
+    #[inline]
+    pub fn detail(&self, level: usize) -> &[T] {
+        let i = self.band(level);
+        &self.coefficients[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// Return the detail coefficients at a level for modification.
+    ///
+    /// The function panics if the level is not between one and the number of
+    /// levels.
+    #[inline]
+    pub fn detail_mut(&mut self, level: usize) -> &mut [T] {
+        let i = self.band(level);
+        &mut self.coefficients[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// Return an iterator over the detail coefficients.
+    #[inline]
+    pub fn details(&self) -> Details<'_, T> {
+        Details { decomposition: self, level: self.level() }
+    }
+
+    /// Return all the coefficients in the order used by `decompose`.
+    #[inline]
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Return the number of levels.
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.offsets.len() - 2
+    }
+
+    /// Return the mode.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Return the number of points of the signal.
+    #[inline]
+    pub fn signal_length(&self) -> usize {
+        self.length
+    }
+
+    // Return the index of the band with the detail coefficients at a level.
+    fn band(&self, level: usize) -> usize {
+        assert!(level > 0 && level <= self.level(), "level {} is out of range", level);
+        self.level() - level + 1
+    }
+}
+
+impl<'l, T> Iterator for Details<'l, T> {
+    type Item = (usize, &'l [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.level == 0 {
+            return None;
+        }
+        let level = self.level;
+        self.level -= 1;
+        Some((level, self.decomposition.detail(level)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.level, Some(self.level))
+    }
+}
+
+impl<'l, T> ExactSizeIterator for Details<'l, T> {}
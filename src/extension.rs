@@ -0,0 +1,387 @@
+use num::Float;
+
+use {Error, Mode, Operation, Result, Transform};
+use transform::{forward_step, inverse_step, reflect};
+use wavelet::{Filter, Wavelet};
+
+/// Decompose a signal.
+///
+/// The coefficients are returned in the order used by the transform, that is,
+/// the approximation coefficients at the deepest level followed by the detail
+/// coefficients from the deepest level to the shallowest one. The number of
+/// points is arbitrary, and `n` below stands for the number of points or
+/// approximation coefficients at the previous level.
+///
+/// If the mode is periodization, each level has `(n + 1) / 2` approximation and
+/// as many detail coefficients; if `n` is odd, the last point is repeated. If
+/// the mode is whole-sample symmetric, the number of coefficients is the same
+/// as the number of points; see `Transform::transform_with_mode`. Otherwise,
+/// the signal is extended beyond its ends according to the mode, and each
+/// level has `(n + L - 1) / 2` approximation and as many detail coefficients,
+/// where `L` is the length of the window spanned by the filters rounded up to
+/// an even number. The numbers of coefficients follow the convention of
+/// PyWavelets.
+///
+/// The function panics if the decomposition is not possible; see
+/// `try_decompose`.
+pub fn decompose<T>(data: &[T], wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Vec<T>
+    where T: Float
+{
+    try_decompose(data, wavelet, level, mode).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Reconstruct a signal.
+///
+/// The coefficients are assumed to be the ones returned by `decompose` for a
+/// signal with `length` points.
+///
+/// The function panics if the reconstruction is not possible; see
+/// `try_reconstruct`.
+pub fn reconstruct<T>(coefficients: &[T], wavelet: &Wavelet<T>, level: usize, mode: Mode,
+                      length: usize) -> Vec<T>
+    where T: Float
+{
+    try_reconstruct(coefficients, wavelet, level, mode, length)
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Decompose a signal if possible.
+pub fn try_decompose<T>(data: &[T], wavelet: &Wavelet<T>, level: usize,
+                        mode: Mode) -> Result<Vec<T>>
+    where T: Float
+{
+    if let Mode::WholeSampleSymmetric = mode {
+        let mut data = data.to_vec();
+        data.try_transform_with_mode(Operation::Forward, wavelet, level, mode)?;
+        return Ok(data);
+    }
+    if data.is_empty() {
+        return Err(Error::InvalidLength(0));
+    }
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    if level > usize::BITS as usize {
+        return Err(Error::LevelTooDeep(level));
+    }
+    let window = Window::new(wavelet);
+    let mut approximation = data.to_vec();
+    let mut details = Vec::with_capacity(level);
+    for _ in 0..level {
+        let (next, detail) = match mode {
+            Mode::Periodization => forward_periodic(approximation, wavelet),
+            _ => window.forward(&approximation, wavelet, mode),
+        };
+        details.push(detail);
+        approximation = next;
+    }
+    Ok(details.into_iter().rev().fold(approximation, |mut coefficients, detail| {
+        coefficients.extend(detail);
+        coefficients
+    }))
+}
+
+/// Reconstruct a signal if possible.
+pub fn try_reconstruct<T>(coefficients: &[T], wavelet: &Wavelet<T>, level: usize, mode: Mode,
+                          length: usize) -> Result<Vec<T>>
+    where T: Float
+{
+    if let Mode::WholeSampleSymmetric = mode {
+        if coefficients.len() != length {
+            return Err(Error::InvalidBufferSize { expected: length, found: coefficients.len() });
+        }
+        let mut data = coefficients.to_vec();
+        data.try_transform_with_mode(Operation::Inverse, wavelet, level, mode)?;
+        return Ok(data);
+    }
+    if length == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    if level > usize::BITS as usize {
+        return Err(Error::LevelTooDeep(level));
+    }
+    let window = Window::new(wavelet);
+    let lengths = lengths(wavelet, length, level, mode);
+    let expected = lengths[level] + lengths[1..].iter().sum::<usize>();
+    if coefficients.len() != expected {
+        return Err(Error::InvalidBufferSize { expected, found: coefficients.len() });
+    }
+    let mut approximation = coefficients[..lengths[level]].to_vec();
+    let mut position = lengths[level];
+    for i in (0..level).rev() {
+        let detail = &coefficients[position..(position + lengths[i + 1])];
+        approximation = match mode {
+            Mode::Periodization => inverse_periodic(approximation, detail, wavelet, lengths[i]),
+            _ => window.inverse(&approximation, detail, wavelet, lengths[i]),
+        };
+        position += lengths[i + 1];
+    }
+    Ok(approximation)
+}
+
+/// Perform one level of the forward transform.
+///
+/// The approximation and detail coefficients are returned separately, and
+/// their numbers are the ones given in the description of `decompose`.
+///
+/// The function panics if the transform is not possible; see `try_dwt`.
+pub fn dwt<T>(data: &[T], wavelet: &Wavelet<T>, mode: Mode) -> (Vec<T>, Vec<T>) where T: Float {
+    try_dwt(data, wavelet, mode).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform one level of the inverse transform.
+///
+/// The approximation and detail coefficients are assumed to be the ones
+/// returned by `dwt` for a signal with `length` points, and an absent band is
+/// treated as zeros.
+///
+/// The function panics if the transform is not possible; see `try_idwt`.
+pub fn idwt<T>(approximation: Option<&[T]>, detail: Option<&[T]>, wavelet: &Wavelet<T>,
+               mode: Mode, length: usize) -> Vec<T>
+    where T: Float
+{
+    try_idwt(approximation, detail, wavelet, mode, length)
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform one level of the forward transform if possible.
+pub fn try_dwt<T>(data: &[T], wavelet: &Wavelet<T>, mode: Mode) -> Result<(Vec<T>, Vec<T>)>
+    where T: Float
+{
+    if let Mode::WholeSampleSymmetric = mode {
+        let mut data = data.to_vec();
+        data.try_transform_with_mode(Operation::Forward, wavelet, 1, mode)?;
+        let detail = data.split_off(data.len().div_ceil(2));
+        return Ok((data, detail));
+    }
+    if data.is_empty() {
+        return Err(Error::InvalidLength(0));
+    }
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    Ok(match mode {
+        Mode::Periodization => forward_periodic(data.to_vec(), wavelet),
+        _ => Window::new(wavelet).forward(data, wavelet, mode),
+    })
+}
+
+/// Perform one level of the inverse transform if possible.
+pub fn try_idwt<T>(approximation: Option<&[T]>, detail: Option<&[T]>, wavelet: &Wavelet<T>,
+                   mode: Mode, length: usize) -> Result<Vec<T>>
+    where T: Float
+{
+    if length == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    let sizes = sizes(wavelet, length, 1, mode);
+    let zeros = vec![T::zero(); if approximation.is_some() && detail.is_some() { 0 } else { sizes[0] }];
+    let approximation = approximation.unwrap_or(&zeros);
+    let detail = detail.unwrap_or_else(|| &zeros[..sizes[1]]);
+    for (band, &expected) in [approximation, detail].iter().zip(&sizes) {
+        if band.len() != expected {
+            return Err(Error::InvalidBufferSize { expected, found: band.len() });
+        }
+    }
+    Ok(match mode {
+        Mode::WholeSampleSymmetric => {
+            let mut data = [approximation, detail].concat();
+            data.try_transform_with_mode(Operation::Inverse, wavelet, 1, mode)?;
+            data
+        },
+        Mode::Periodization => inverse_periodic(approximation.to_vec(), detail, wavelet, length),
+        _ => Window::new(wavelet).inverse(approximation, detail, wavelet, length),
+    })
+}
+
+/// Compute the maximum useful level of decomposition.
+///
+/// The level is the deepest one at which the window spanned by the filters,
+/// which is the filter length for the built-in wavelets, still fits the
+/// approximation coefficients, which is the rule of `dwt_max_level` in
+/// PyWavelets.
+pub fn max_level<T>(length: usize, wavelet: &Wavelet<T>) -> usize {
+    let size = Window::new(wavelet).length.saturating_sub(1);
+    if size == 0 {
+        return 0;
+    }
+    let mut level = 0;
+    while length >> (level + 1) >= size {
+        level += 1;
+    }
+    level
+}
+
+// Return the numbers of coefficients in the order in which the bands are stored
+// by `decompose`.
+pub(crate) fn sizes<T>(wavelet: &Wavelet<T>, length: usize, level: usize, mode: Mode) -> Vec<usize> {
+    let lengths = lengths(wavelet, length, level, mode);
+    let mut sizes = vec![lengths[level]];
+    for i in (0..level).rev() {
+        sizes.push(match mode {
+            Mode::WholeSampleSymmetric => lengths[i] - lengths[i + 1],
+            _ => lengths[i + 1],
+        });
+    }
+    sizes
+}
+
+// Return the number of points followed by the numbers of approximation
+// coefficients at each level.
+fn lengths<T>(wavelet: &Wavelet<T>, length: usize, level: usize, mode: Mode) -> Vec<usize> {
+    let window = Window::new(wavelet);
+    let mut lengths = vec![length];
+    for i in 0..level {
+        let length = match mode {
+            Mode::Periodization | Mode::WholeSampleSymmetric => lengths[i].div_ceil(2),
+            _ => window.next(lengths[i]),
+        };
+        lengths.push(length);
+    }
+    lengths
+}
+
+fn forward_periodic<T>(mut data: Vec<T>, wavelet: &Wavelet<T>) -> (Vec<T>, Vec<T>)
+    where T: Float
+{
+    if data.len() % 2 == 1 {
+        let last = data[data.len() - 1];
+        data.push(last);
+    }
+    let n = data.len();
+    let mut work = vec![T::zero(); n];
+    forward_step(&mut data, wavelet, n, &mut work, Mode::Periodization);
+    let detail = data.split_off(n / 2);
+    (data, detail)
+}
+
+fn inverse_periodic<T>(mut data: Vec<T>, detail: &[T], wavelet: &Wavelet<T>,
+                       length: usize) -> Vec<T>
+    where T: Float
+{
+    data.extend_from_slice(detail);
+    let n = data.len();
+    let mut work = vec![T::zero(); n];
+    inverse_step(&mut data, wavelet, n, &mut work, Mode::Periodization);
+    data.truncate(length);
+    data
+}
+
+// The window spanned by the filters of a wavelet. Coefficient `i` is computed
+// from the samples starting at `2 * i + start - offset`, where `offset` is the
+// one of the filter.
+struct Window {
+    length: usize,
+    start: isize,
+}
+
+impl Window {
+    fn new<T>(wavelet: &Wavelet<T>) -> Window {
+        let filters = [&wavelet.dec_lo, &wavelet.dec_hi, &wavelet.rec_lo, &wavelet.rec_hi];
+        let before = filters.iter().map(|filter| filter.offset).max().unwrap();
+        let after = filters.iter().map(|filter| filter.len().saturating_sub(filter.offset));
+        let after = after.max().unwrap();
+        let length = (before + after + 1) & !1;
+        Window { length, start: before as isize + 2 - length as isize }
+    }
+
+    #[inline(always)]
+    fn next(&self, length: usize) -> usize {
+        (length + self.length - 1) / 2
+    }
+
+    fn forward<T>(&self, data: &[T], wavelet: &Wavelet<T>, mode: Mode) -> (Vec<T>, Vec<T>)
+        where T: Float
+    {
+        let length = self.next(data.len());
+        let approximation = (0..length).map(|i| self.analyze(data, &wavelet.dec_lo, i, mode));
+        let detail = (0..length).map(|i| self.analyze(data, &wavelet.dec_hi, i, mode));
+        (approximation.collect(), detail.collect())
+    }
+
+    fn inverse<T>(&self, approximation: &[T], detail: &[T], wavelet: &Wavelet<T>,
+                  length: usize) -> Vec<T>
+        where T: Float
+    {
+        let mut data = vec![T::zero(); length];
+        for (i, (&approximation, &detail)) in approximation.iter().zip(detail).enumerate() {
+            self.synthesize(&mut data, &wavelet.rec_lo, i, approximation);
+            self.synthesize(&mut data, &wavelet.rec_hi, i, detail);
+        }
+        data
+    }
+
+    #[inline(always)]
+    fn analyze<T>(&self, data: &[T], filter: &Filter<T>, i: usize, mode: Mode) -> T
+        where T: Float
+    {
+        let k = 2 * i as isize + self.start - filter.offset as isize;
+        let mut sum = T::zero();
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            sum = sum + coefficient * extend(data, k + j as isize, mode);
+        }
+        sum
+    }
+
+    #[inline(always)]
+    fn synthesize<T>(&self, data: &mut [T], filter: &Filter<T>, i: usize, value: T)
+        where T: Float
+    {
+        let n = data.len() as isize;
+        let k = 2 * i as isize + self.start - filter.offset as isize;
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            let k = k + j as isize;
+            if k >= 0 && k < n {
+                data[k as usize] = data[k as usize] + coefficient * value;
+            }
+        }
+    }
+}
+
+// Return the sample at position `i` of the signal extended according to the
+// mode.
+#[inline(always)]
+fn extend<T>(data: &[T], i: isize, mode: Mode) -> T where T: Float {
+    let n = data.len() as isize;
+    if i >= 0 && i < n {
+        return data[i as usize];
+    }
+    match mode {
+        Mode::Periodization => data[i.rem_euclid(n) as usize],
+        Mode::Symmetric => {
+            let i = i.rem_euclid(2 * n);
+            data[(if i < n { i } else { 2 * n - 1 - i }) as usize]
+        },
+        Mode::WholeSampleSymmetric => data[reflect(i, data.len())],
+        Mode::Zero => T::zero(),
+        Mode::Constant => data[if i < 0 { 0 } else { data.len() - 1 }],
+        Mode::Smooth => {
+            if n == 1 {
+                return data[0];
+            }
+            let (k, j, l) = if i < 0 { (-i, 0, 1) } else { (i - n + 1, n - 1, n - 2) };
+            let (first, second) = (data[j as usize], data[l as usize]);
+            first + T::from(k).unwrap() * (first - second)
+        },
+        Mode::Antisymmetric => {
+            let i = i.rem_euclid(2 * n);
+            if i < n { data[i as usize] } else { -data[(2 * n - 1 - i) as usize] }
+        },
+        Mode::AntiReflect => {
+            if n == 1 {
+                return data[0];
+            }
+            let (first, last) = (data[0], data[(n - 1) as usize]);
+            let m = n - 1;
+            let (k, i) = (i.div_euclid(2 * m), i.rem_euclid(2 * m));
+            let value = if i <= m { data[i as usize] } else { last + last - data[(2 * m - i) as usize] };
+            value + T::from(2 * k).unwrap() * (last - first)
+        },
+    }
+}
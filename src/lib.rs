@@ -8,14 +8,56 @@
 extern crate num_traits as num;
 
 use num::Float;
+use std::{error, fmt};
 
+mod decomposition;
+mod extension;
+mod packet;
+mod plan;
+mod stationary;
 mod transform;
 
 pub mod wavelet;
 
 use wavelet::Wavelet;
 
-pub use transform::Transform;
+pub use decomposition::{Decomposition, Details, try_wavedec, try_waverec, wavedec, waverec};
+pub use extension::{decompose, dwt, idwt, max_level, reconstruct, try_decompose, try_dwt, try_idwt,
+                    try_reconstruct};
+pub use packet::{Order, WaveletPacket};
+pub use plan::Plan;
+pub use stationary::{Levels, imodwt, iswt, modwt, mra, swt, try_imodwt, try_iswt, try_modwt,
+                     try_mra, try_swt};
+pub use transform::{Transform, transform_into, transform_into_with_mode, transform_to_vec,
+                    try_transform_into, try_transform_into_with_mode, workspace_size};
+
+/// An error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The wavelet is unknown.
+    UnknownWavelet(String),
+    /// The number of points is not supported.
+    InvalidLength(usize),
+    /// The level is too deep for the number of points.
+    LevelTooDeep(usize),
+    /// The filters of the wavelet are inconsistent with each other or with the
+    /// mode.
+    InvalidWavelet,
+    /// The mode is not supported by the operation.
+    UnsupportedMode(Mode),
+    /// The size of a buffer is not the expected one.
+    InvalidBufferSize {
+        /// The expected size.
+        expected: usize,
+        /// The actual size.
+        found: usize,
+    },
+    /// The path does not address a node of a wavelet packet decomposition.
+    InvalidPath(String),
+}
+
+/// A result.
+pub type Result<T> = std::result::Result<T, Error>;
 
 /// A transform operation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -26,6 +68,37 @@ pub enum Operation {
     Inverse,
 }
 
+/// A signal extension mode.
+///
+/// Except for periodization and whole-sample symmetric extension, the number of
+/// coefficients grows at each level; see `decompose`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// The signal is extended periodically, and the number of coefficients is
+    /// the same as the number of points.
+    Periodization,
+    /// The signal is extended by mirroring it about its ends with the end
+    /// points repeated.
+    Symmetric,
+    /// The signal is extended with zeros.
+    Zero,
+    /// The signal is extended by repeating its end points.
+    Constant,
+    /// The signal is extended linearly with the slopes at its ends.
+    Smooth,
+    /// The signal is extended by mirroring it about its ends with the end
+    /// points repeated and the sign changed.
+    Antisymmetric,
+    /// The signal is extended by mirroring it about its end points with the
+    /// sign changed, that is, by reflecting it about the end points in the
+    /// plane.
+    AntiReflect,
+    /// The signal is extended by mirroring it about its end points, and the
+    /// number of coefficients is the same as the number of points; see
+    /// `Transform::transform_with_mode`.
+    WholeSampleSymmetric,
+}
+
 /// Perform the transform.
 ///
 /// The function is a shortcut for `Transform::transform`.
@@ -35,3 +108,49 @@ pub fn transform<T>(data: &mut [T], operation: Operation, wavelet: &Wavelet<T>,
 {
     Transform::transform(data, operation, wavelet, level);
 }
+
+/// Perform the transform if possible.
+///
+/// The function is a shortcut for `Transform::try_transform`.
+#[inline(always)]
+pub fn try_transform<T>(data: &mut [T], operation: Operation, wavelet: &Wavelet<T>,
+                        level: usize) -> Result<()>
+    where T: Float
+{
+    Transform::try_transform(data, operation, wavelet, level)
+}
+
+/// Perform the transform using a workspace.
+///
+/// The function is a shortcut for `Transform::transform_with_workspace` with
+/// periodization.
+#[inline(always)]
+pub fn transform_with_workspace<T>(data: &mut [T], workspace: &mut [T], operation: Operation,
+                                   wavelet: &Wavelet<T>, level: usize)
+    where T: Float
+{
+    Transform::transform_with_workspace(data, workspace, operation, wavelet, level,
+                                        Mode::Periodization);
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownWavelet(ref name) => write!(formatter, "unknown wavelet {:?}", name),
+            Error::InvalidLength(length) => {
+                write!(formatter, "unsupported number of points ({})", length)
+            },
+            Error::LevelTooDeep(level) => {
+                write!(formatter, "level {} is too deep for the number of points", level)
+            },
+            Error::InvalidWavelet => write!(formatter, "inconsistent filters"),
+            Error::UnsupportedMode(mode) => write!(formatter, "unsupported mode {:?}", mode),
+            Error::InvalidBufferSize { expected, found } => {
+                write!(formatter, "expected a buffer of size {}, found {}", expected, found)
+            },
+            Error::InvalidPath(ref path) => write!(formatter, "invalid node path {:?}", path),
+        }
+    }
+}
+
+impl error::Error for Error {}
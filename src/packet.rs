@@ -0,0 +1,191 @@
+use num::Float;
+
+use {Error, Mode, Result};
+use transform::{check, forward_step, inverse_step};
+use wavelet::Wavelet;
+
+/// A wavelet packet decomposition.
+///
+/// Both the approximation and detail coefficients are split at each level,
+/// which gives `2^level` nodes at level `level`. A node is addressed by a path,
+/// which is a string of `a` and `d` standing for the approximation and detail
+/// bands taken from the root to the node, or by a level and an index in one of
+/// the orders. The root is the signal itself and has the empty path. The modes
+/// are the ones of `Transform::transform_with_mode`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveletPacket<T> {
+    nodes: Vec<Vec<T>>,
+    level: usize,
+    mode: Mode,
+}
+
+/// An order of the nodes at a level.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Order {
+    /// The nodes are ordered by their paths with `a` before `d`.
+    Natural,
+    /// The nodes are ordered by the frequency bands that they cover, which is
+    /// the Gray code of the natural order.
+    Frequency,
+}
+
+impl<T> WaveletPacket<T> where T: Float {
+    /// Decompose a signal if possible.
+    pub fn new(data: &[T], wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Result<Self> {
+        let n = data.len();
+        if level > 0 {
+            check(n, wavelet, level, mode)?;
+            if n >> level == 0 {
+                return Err(Error::LevelTooDeep(level));
+            }
+        }
+        let mut nodes = Vec::with_capacity((2 << level) - 1);
+        nodes.push(data.to_vec());
+        let mut work = vec![T::zero(); n];
+        for i in 0..((1 << level) - 1) {
+            let mut approximation = nodes[i].clone();
+            let m = approximation.len();
+            forward_step(&mut approximation, wavelet, m, &mut work, mode);
+            let detail = approximation.split_off((m + 1) >> 1);
+            nodes.push(approximation);
+            nodes.push(detail);
+        }
+        Ok(WaveletPacket { nodes, level, mode })
+    }
+
+    /// Reconstruct the signal from a subset of the nodes if possible.
+    ///
+    /// The coefficients of a node are taken as they are if its path is given,
+    /// reconstructed from its children otherwise, and treated as zeros if the
+    /// node is at the deepest level and not given. Consequently, the signal is
+    /// recovered if the given nodes cover all the frequency bands, such as the
+    /// nodes at one level, and the descendants of a given node are ignored. The
+    /// wavelet should be the one used for the decomposition.
+    pub fn reconstruct(&self, wavelet: &Wavelet<T>, paths: &[&str]) -> Result<Vec<T>> {
+        let mut selected = vec![false; self.nodes.len()];
+        for &path in paths {
+            match self.locate(path) {
+                Some((level, index)) => selected[position(level, index)] = true,
+                _ => return Err(Error::InvalidPath(path.to_string())),
+            }
+        }
+        let n = self.nodes[0].len();
+        if self.level > 0 {
+            check(n, wavelet, self.level, self.mode)?;
+        }
+        let mut work = vec![T::zero(); n];
+        let data = self.rebuild(wavelet, &selected, 0, 0, &mut work);
+        Ok(data.unwrap_or_else(|| vec![T::zero(); n]))
+    }
+
+    /// Return the coefficients of a node given its path.
+    #[inline]
+    pub fn node(&self, path: &str) -> Option<&[T]> {
+        let (level, index) = self.locate(path)?;
+        Some(&self.nodes[position(level, index)])
+    }
+
+    /// Return the coefficients of a node given its path for modification.
+    #[inline]
+    pub fn node_mut(&mut self, path: &str) -> Option<&mut [T]> {
+        let (level, index) = self.locate(path)?;
+        Some(&mut self.nodes[position(level, index)])
+    }
+
+    /// Return the coefficients of a node given its level and index.
+    #[inline]
+    pub fn node_at(&self, level: usize, index: usize, order: Order) -> Option<&[T]> {
+        if level > self.level || index >> level != 0 {
+            return None;
+        }
+        Some(&self.nodes[position(level, natural(index, order))])
+    }
+
+    /// Return the paths and coefficients of the nodes at a level.
+    ///
+    /// The function panics if the level is deeper than the one of the
+    /// decomposition.
+    pub fn nodes(&self, level: usize, order: Order) -> Vec<(String, &[T])> {
+        assert!(level <= self.level, "level {} is out of range", level);
+        (0..(1 << level)).map(|index| {
+            let index = natural(index, order);
+            (path(level, index), &self.nodes[position(level, index)][..])
+        }).collect()
+    }
+
+    /// Return the number of levels.
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Return the mode.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Return the number of points of the signal.
+    #[inline]
+    pub fn signal_length(&self) -> usize {
+        self.nodes[0].len()
+    }
+
+    fn locate(&self, path: &str) -> Option<(usize, usize)> {
+        if path.len() > self.level {
+            return None;
+        }
+        path.chars().try_fold((0, 0), |(level, index), band| match band {
+            'a' => Some((level + 1, index << 1)),
+            'd' => Some((level + 1, (index << 1) | 1)),
+            _ => None,
+        })
+    }
+
+    fn rebuild(&self, wavelet: &Wavelet<T>, selected: &[bool], level: usize, index: usize,
+               work: &mut [T]) -> Option<Vec<T>> {
+        let node = &self.nodes[position(level, index)];
+        if selected[position(level, index)] {
+            return Some(node.clone());
+        }
+        if level == self.level {
+            return None;
+        }
+        let approximation = self.rebuild(wavelet, selected, level + 1, index << 1, work);
+        let detail = self.rebuild(wavelet, selected, level + 1, (index << 1) | 1, work);
+        if approximation.is_none() && detail.is_none() {
+            return None;
+        }
+        let m = node.len();
+        let mut data = vec![T::zero(); m];
+        {
+            let (first, second) = data.split_at_mut((m + 1) >> 1);
+            if let Some(approximation) = approximation {
+                first.copy_from_slice(&approximation);
+            }
+            if let Some(detail) = detail {
+                second.copy_from_slice(&detail);
+            }
+        }
+        inverse_step(&mut data, wavelet, m, work, self.mode);
+        Some(data)
+    }
+}
+
+// Return the position of a node in the tree stored level by level.
+#[inline(always)]
+fn position(level: usize, index: usize) -> usize {
+    (1 << level) - 1 + index
+}
+
+#[inline(always)]
+fn natural(index: usize, order: Order) -> usize {
+    match order {
+        Order::Natural => index,
+        Order::Frequency => index ^ (index >> 1),
+    }
+}
+
+fn path(level: usize, index: usize) -> String {
+    (0..level).rev().map(|i| if (index >> i) & 1 == 0 { 'a' } else { 'd' }).collect()
+}
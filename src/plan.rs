@@ -0,0 +1,78 @@
+use num::Float;
+
+use {Error, Mode, Operation, Result};
+use transform::{check, run};
+use wavelet::Wavelet;
+
+/// A plan of the transform.
+///
+/// A plan is created once for a number of points, a wavelet, a level, and a
+/// mode, and it owns the buffer needed by the transform, which makes it
+/// possible to transform many signals of the same size without allocation. The
+/// conventions are the ones of `Transform::transform_with_mode`.
+pub struct Plan<T> {
+    wavelet: Wavelet<T>,
+    level: usize,
+    mode: Mode,
+    work: Vec<T>,
+}
+
+impl<T> Plan<T> where T: Float {
+    /// Create a plan if the transform is possible.
+    pub fn new(length: usize, wavelet: Wavelet<T>, level: usize, mode: Mode) -> Result<Self> {
+        if level > 0 {
+            check(length, &wavelet, level, mode)?;
+        }
+        Ok(Plan { wavelet, level, mode, work: vec![T::zero(); length] })
+    }
+
+    /// Perform the forward transform.
+    ///
+    /// The function panics if the number of points is not the one of the plan.
+    #[inline]
+    pub fn forward(&mut self, data: &mut [T]) {
+        self.execute(data, Operation::Forward);
+    }
+
+    /// Perform the inverse transform.
+    ///
+    /// The function panics if the number of points is not the one of the plan.
+    #[inline]
+    pub fn inverse(&mut self, data: &mut [T]) {
+        self.execute(data, Operation::Inverse);
+    }
+
+    /// Perform the transform.
+    ///
+    /// The function panics if the number of points is not the one of the plan.
+    pub fn execute(&mut self, data: &mut [T], operation: Operation) {
+        if data.len() != self.work.len() {
+            panic!("{}", Error::InvalidBufferSize { expected: self.work.len(), found: data.len() });
+        }
+        run(data, operation, &self.wavelet, self.level, self.mode, &mut self.work);
+    }
+
+    /// Return the number of points.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.work.len()
+    }
+
+    /// Return the wavelet.
+    #[inline]
+    pub fn wavelet(&self) -> &Wavelet<T> {
+        &self.wavelet
+    }
+
+    /// Return the level.
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Return the mode.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+}
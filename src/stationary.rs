@@ -0,0 +1,256 @@
+use num::Float;
+
+use {Error, Mode, Result};
+use transform::check;
+use wavelet::{Filter, Wavelet};
+
+/// The approximation and detail coefficients of the stationary transform at
+/// each level.
+pub type Levels<T> = Vec<(Vec<T>, Vec<T>)>;
+
+/// Perform the stationary wavelet transform.
+///
+/// The transform is computed by the à trous algorithm: the filters are dilated
+/// by inserting `2^(j - 1) - 1` zeros between their coefficients at level `j`,
+/// and no decimation is done. Each level has as many approximation and detail
+/// coefficients as there are points, and the levels are returned from the
+/// deepest to the shallowest one. The data are extended periodically, and the
+/// coefficients at level `j` with indices divisible by `2^j` are the ones of
+/// `Transform::transform`. The number of points should be divisible by
+/// `2^level`.
+///
+/// The function panics if the transform is not possible; see `try_swt`.
+pub fn swt<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Levels<T> where T: Float {
+    try_swt(data, wavelet, level).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform the inverse stationary wavelet transform.
+///
+/// The coefficients are assumed to be the ones returned by `swt`. Only the
+/// approximation coefficients at the deepest level and the detail coefficients
+/// at all levels are used, and the result is averaged over the shifts that the
+/// decimated transform would have to choose from.
+///
+/// The function panics if the transform is not possible; see `try_iswt`.
+pub fn iswt<T>(coefficients: &[(Vec<T>, Vec<T>)], wavelet: &Wavelet<T>) -> Vec<T>
+    where T: Float
+{
+    try_iswt(coefficients, wavelet).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform the stationary wavelet transform if possible.
+pub fn try_swt<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Result<Levels<T>>
+    where T: Float
+{
+    if level > 0 {
+        check(data.len(), wavelet, level, Mode::Periodization)?;
+    }
+    Ok(forward(data, wavelet, level, T::one()))
+}
+
+/// Perform the inverse stationary wavelet transform if possible.
+pub fn try_iswt<T>(coefficients: &[(Vec<T>, Vec<T>)], wavelet: &Wavelet<T>) -> Result<Vec<T>>
+    where T: Float
+{
+    let n = validate(coefficients)?;
+    check(n, wavelet, coefficients.len(), Mode::Periodization)?;
+    let details = coefficients.iter().map(|(_, detail)| Some(&detail[..])).collect::<Vec<_>>();
+    Ok(inverse(Some(&coefficients[0].0), &details, wavelet, n, T::from(0.5).unwrap()))
+}
+
+/// Perform the maximal overlap discrete wavelet transform.
+///
+/// The transform is the stationary one with the filters divided by `sqrt(2)`,
+/// that is, the coefficients at level `j` are the ones of `swt` divided by
+/// `2^(j / 2)`, which makes the energy of the coefficients at all levels equal
+/// to the one of the data. The number of points is arbitrary.
+///
+/// The function panics if the transform is not possible; see `try_modwt`.
+pub fn modwt<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Levels<T> where T: Float {
+    try_modwt(data, wavelet, level).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform the inverse maximal overlap discrete wavelet transform.
+///
+/// The coefficients are assumed to be the ones returned by `modwt`, and only
+/// the approximation coefficients at the deepest level and the detail
+/// coefficients at all levels are used.
+///
+/// The function panics if the transform is not possible; see `try_imodwt`.
+pub fn imodwt<T>(coefficients: &[(Vec<T>, Vec<T>)], wavelet: &Wavelet<T>) -> Vec<T>
+    where T: Float
+{
+    try_imodwt(coefficients, wavelet).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Compute the multiresolution analysis based on the maximal overlap discrete
+/// wavelet transform.
+///
+/// The components are the details at levels from one to `level` followed by
+/// the smooth at level `level`. Each component is the inverse transform of the
+/// coefficients of one band with the other bands set to zero, which makes the
+/// components aligned in time with the data and their sum equal to the data.
+///
+/// The function panics if the analysis is not possible; see `try_mra`.
+pub fn mra<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Vec<Vec<T>> where T: Float {
+    try_mra(data, wavelet, level).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Perform the maximal overlap discrete wavelet transform if possible.
+pub fn try_modwt<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Result<Levels<T>>
+    where T: Float
+{
+    check_overlap(data.len(), wavelet, level)?;
+    Ok(forward(data, wavelet, level, T::from(0.5).unwrap().sqrt()))
+}
+
+/// Perform the inverse maximal overlap discrete wavelet transform if possible.
+pub fn try_imodwt<T>(coefficients: &[(Vec<T>, Vec<T>)], wavelet: &Wavelet<T>) -> Result<Vec<T>>
+    where T: Float
+{
+    let n = validate(coefficients)?;
+    check_overlap(n, wavelet, coefficients.len())?;
+    let details = coefficients.iter().map(|(_, detail)| Some(&detail[..])).collect::<Vec<_>>();
+    Ok(inverse(Some(&coefficients[0].0), &details, wavelet, n, T::from(0.5).unwrap().sqrt()))
+}
+
+/// Compute the multiresolution analysis if possible.
+pub fn try_mra<T>(data: &[T], wavelet: &Wavelet<T>, level: usize) -> Result<Vec<Vec<T>>>
+    where T: Float
+{
+    let n = data.len();
+    check_overlap(n, wavelet, level)?;
+    let gain = T::from(0.5).unwrap().sqrt();
+    let coefficients = forward(data, wavelet, level, gain);
+    let mut components = Vec::with_capacity(level + 1);
+    let mut details = vec![None; level];
+    for j in (0..level).rev() {
+        details[j] = Some(&coefficients[j].1[..]);
+        components.push(inverse(None, &details, wavelet, n, gain));
+        details[j] = None;
+    }
+    if level == 0 {
+        components.push(data.to_vec());
+    } else {
+        components.push(inverse(Some(&coefficients[0].0), &details, wavelet, n, gain));
+    }
+    Ok(components)
+}
+
+// Compute the levels from the deepest to the shallowest one with the filters
+// multiplied by a gain.
+fn forward<T>(data: &[T], wavelet: &Wavelet<T>, level: usize, gain: T) -> Levels<T>
+    where T: Float
+{
+    let n = data.len();
+    let mut levels: Levels<T> = Vec::with_capacity(level);
+    for j in 0..level {
+        let coefficients = {
+            let data = if j == 0 { data } else { &levels[j - 1].0 };
+            let mut approximation = vec![T::zero(); n];
+            let mut detail = vec![T::zero(); n];
+            analyze(data, &wavelet.dec_lo, 1 << j, gain, &mut approximation);
+            analyze(data, &wavelet.dec_hi, 1 << j, gain, &mut detail);
+            (approximation, detail)
+        };
+        levels.push(coefficients);
+    }
+    levels.reverse();
+    levels
+}
+
+// Reconstruct the data from the approximation coefficients at the deepest level
+// and the detail coefficients from the deepest level to the shallowest one with
+// the filters multiplied by a gain. An absent band is treated as zeros.
+fn inverse<T>(approximation: Option<&[T]>, details: &[Option<&[T]>], wavelet: &Wavelet<T>,
+              n: usize, gain: T) -> Vec<T>
+    where T: Float
+{
+    let level = details.len();
+    let mut data = approximation.map(|data| data.to_vec()).unwrap_or_else(|| vec![T::zero(); n]);
+    let mut work = vec![T::zero(); n];
+    for (j, detail) in details.iter().enumerate() {
+        let step = 1 << (level - j - 1);
+        for value in work.iter_mut() {
+            *value = T::zero();
+        }
+        synthesize(&mut work, &wavelet.rec_lo, step, gain, &data);
+        if let Some(detail) = *detail {
+            synthesize(&mut work, &wavelet.rec_hi, step, gain, detail);
+        }
+        data.copy_from_slice(&work);
+    }
+    data
+}
+
+// Check that the levels are nonempty and have bands of the same size, which is
+// returned.
+fn validate<T>(coefficients: &[(Vec<T>, Vec<T>)]) -> Result<usize> {
+    if coefficients.is_empty() {
+        return Err(Error::InvalidLength(0));
+    }
+    let n = coefficients[0].0.len();
+    for (approximation, detail) in coefficients {
+        for band in &[approximation, detail] {
+            if band.len() != n {
+                return Err(Error::InvalidBufferSize { expected: n, found: band.len() });
+            }
+        }
+    }
+    Ok(n)
+}
+
+// Check that the maximal overlap transform is possible, which requires the
+// indices of the samples reached by the dilated filters at the deepest level to
+// be representable.
+fn check_overlap<T>(n: usize, wavelet: &Wavelet<T>, level: usize) -> Result<()> where T: Float {
+    if n == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    if level == 0 {
+        return Ok(());
+    }
+    if level > usize::BITS as usize {
+        return Err(Error::LevelTooDeep(level));
+    }
+    let filters = [&wavelet.dec_lo, &wavelet.dec_hi, &wavelet.rec_lo, &wavelet.rec_hi];
+    let span = filters.iter().map(|filter| filter.len() - 1).max().unwrap();
+    let step: usize = 1 << (level - 1);
+    if step.checked_mul(span).and_then(|reach| reach.checked_add(2 * n)).is_none() {
+        return Err(Error::LevelTooDeep(level));
+    }
+    Ok(())
+}
+
+// Compute coefficient `i` from the samples starting at `i - step * offset` with
+// a stride of `step`.
+fn analyze<T>(data: &[T], filter: &Filter<T>, step: usize, gain: T, coefficients: &mut [T])
+    where T: Float
+{
+    let n = data.len();
+    let shift = n - step * filter.offset % n;
+    for (i, value) in coefficients.iter_mut().enumerate() {
+        let mut sum = T::zero();
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            sum = sum + coefficient * data[(i + shift + step * j) % n];
+        }
+        *value = gain * sum;
+    }
+}
+
+fn synthesize<T>(data: &mut [T], filter: &Filter<T>, step: usize, gain: T, coefficients: &[T])
+    where T: Float
+{
+    let n = data.len();
+    let shift = n - step * filter.offset % n;
+    for (i, &value) in coefficients.iter().enumerate() {
+        let value = gain * value;
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            let k = (i + shift + step * j) % n;
+            data[k] = data[k] + coefficient * value;
+        }
+    }
+}
@@ -1,41 +1,255 @@
 use num::Float;
 
-use Operation;
-use wavelet::Wavelet;
+use {Error, Mode, Operation, Result, max_level};
+use wavelet::{Filter, Wavelet};
 
 /// The transform.
 pub trait Transform<T> {
     /// Perform the transform.
     ///
-    /// The number of points should be divisible by `2^level`. If the operation
-    /// is forward, the data are replaced by the approximation and detail
-    /// coefficients stored in the first and second halves of `data`,
-    /// respectively. If the operation is inverse, the data are assumed to be
-    /// stored according to the above convention.
+    /// The number of points should be divisible by `2^level`; arbitrary numbers
+    /// of points are supported by `decompose` and `reconstruct` and by the
+    /// whole-sample symmetric mode. If the operation is forward, the data are
+    /// replaced by the approximation and detail coefficients stored in the
+    /// first and second halves of `data`, respectively. If the operation is
+    /// inverse, the data are assumed to be stored according to the above
+    /// convention. The data are extended periodically, and the filters of the
+    /// wavelet are allowed to be longer than the data at deep levels; other
+    /// extension modes are supported by `transform_with_mode`, `decompose`, and
+    /// `reconstruct`. The wavelet should be well-formed; see
+    /// `Wavelet::validate`.
+    ///
+    /// The function panics if the transform is not possible; see
+    /// `try_transform`.
     fn transform(&mut self, operation: Operation, wavelet: &Wavelet<T>, level: usize);
+
+    /// Perform the transform with a signal extension mode.
+    ///
+    /// The mode should be either periodization or whole-sample symmetric, which
+    /// are the ones that keep the number of coefficients equal to the number
+    /// of points. The whole-sample symmetric mode requires the filters to have
+    /// odd lengths and to be symmetric about the sample at which they are
+    /// applied for the low-pass filters and about the next one for the
+    /// high-pass filters, which is the case for the JPEG 2000 wavelets and the
+    /// biorthogonal wavelets with even orders. In this mode, the number of
+    /// points is arbitrary as long as there are at least two points at each
+    /// level, and the approximation band is the larger half if the number of
+    /// points at a level is odd.
+    ///
+    /// The function panics if the transform is not possible; see
+    /// `try_transform_with_mode`.
+    fn transform_with_mode(&mut self, operation: Operation, wavelet: &Wavelet<T>, level: usize,
+                           mode: Mode);
+
+    /// Perform the transform at the maximum level.
+    ///
+    /// The level is the one given by `max_level` limited to the deepest one
+    /// for which the number of points is divisible by `2^level`, and it is
+    /// returned. Since the level depends only on the number of points and the
+    /// wavelet, the inverse operation uses the same level as the forward one.
+    fn transform_at_max_level(&mut self, operation: Operation, wavelet: &Wavelet<T>) -> usize;
+
+    /// Perform the transform if possible.
+    fn try_transform(&mut self, operation: Operation, wavelet: &Wavelet<T>,
+                     level: usize) -> Result<()>;
+
+    /// Perform the transform with a signal extension mode if possible.
+    fn try_transform_with_mode(&mut self, operation: Operation, wavelet: &Wavelet<T>,
+                               level: usize, mode: Mode) -> Result<()>;
+
+    /// Perform the transform with a signal extension mode using a workspace.
+    ///
+    /// The workspace should have at least as many elements as given by
+    /// `workspace_size`, and its content is overwritten. No memory is
+    /// allocated.
+    ///
+    /// The function panics if the transform is not possible; see
+    /// `try_transform_with_workspace`.
+    fn transform_with_workspace(&mut self, workspace: &mut [T], operation: Operation,
+                                wavelet: &Wavelet<T>, level: usize, mode: Mode);
+
+    /// Perform the transform with a signal extension mode using a workspace if
+    /// possible.
+    fn try_transform_with_workspace(&mut self, workspace: &mut [T], operation: Operation,
+                                    wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Result<()>;
 }
 
 impl<T> Transform<T> for [T] where T: Float {
+    #[inline(always)]
     fn transform(&mut self, operation: Operation, wavelet: &Wavelet<T>, level: usize) {
+        self.transform_with_mode(operation, wavelet, level, Mode::Periodization);
+    }
+
+    fn transform_with_mode(&mut self, operation: Operation, wavelet: &Wavelet<T>, level: usize,
+                           mode: Mode) {
+        if let Err(error) = self.try_transform_with_mode(operation, wavelet, level, mode) {
+            panic!("{}", error);
+        }
+    }
+
+    fn transform_at_max_level(&mut self, operation: Operation, wavelet: &Wavelet<T>) -> usize {
+        let n = self.len();
+        let level = if n == 0 { 0 } else { max_level(n, wavelet).min(n.trailing_zeros() as usize) };
+        self.transform(operation, wavelet, level);
+        level
+    }
+
+    #[inline(always)]
+    fn try_transform(&mut self, operation: Operation, wavelet: &Wavelet<T>,
+                     level: usize) -> Result<()> {
+        self.try_transform_with_mode(operation, wavelet, level, Mode::Periodization)
+    }
+
+    fn try_transform_with_mode(&mut self, operation: Operation, wavelet: &Wavelet<T>,
+                               level: usize, mode: Mode) -> Result<()> {
+        let mut work = vec![T::zero(); workspace_size(self.len(), level, mode)];
+        self.try_transform_with_workspace(&mut work, operation, wavelet, level, mode)
+    }
+
+    fn transform_with_workspace(&mut self, workspace: &mut [T], operation: Operation,
+                                wavelet: &Wavelet<T>, level: usize, mode: Mode) {
+        let result = self.try_transform_with_workspace(workspace, operation, wavelet, level, mode);
+        if let Err(error) = result {
+            panic!("{}", error);
+        }
+    }
+
+    fn try_transform_with_workspace(&mut self, workspace: &mut [T], operation: Operation,
+                                    wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Result<()> {
         if level == 0 {
-            return;
+            return Ok(());
         }
         let n = self.len();
-        assert!(n % (1 << level) == 0);
-        let mut work = Vec::with_capacity(n);
-        unsafe { work.set_len(n) };
-        match operation {
-            Operation::Forward => {
-                for i in 0..level {
-                    forward_step(self, wavelet, n >> i, &mut work);
-                }
-            },
-            Operation::Inverse => {
-                for i in 0..level {
-                    inverse_step(self, wavelet, n >> (level - i - 1), &mut work);
-                }
-            },
+        check(n, wavelet, level, mode)?;
+        let expected = workspace_size(n, level, mode);
+        if workspace.len() < expected {
+            return Err(Error::InvalidBufferSize { expected, found: workspace.len() });
         }
+        run(self, operation, wavelet, level, mode, workspace);
+        Ok(())
+    }
+}
+
+/// Perform the transform out of place.
+///
+/// The output should have as many elements as the input, which is preserved,
+/// and the conventions are the ones of `Transform::transform`.
+///
+/// The function panics if the transform is not possible; see
+/// `try_transform_into`.
+#[inline(always)]
+pub fn transform_into<T>(input: &[T], output: &mut [T], operation: Operation,
+                         wavelet: &Wavelet<T>, level: usize)
+    where T: Float
+{
+    transform_into_with_mode(input, output, operation, wavelet, level, Mode::Periodization);
+}
+
+/// Perform the transform out of place with a signal extension mode.
+///
+/// The conventions are the ones of `Transform::transform_with_mode`.
+///
+/// The function panics if the transform is not possible; see
+/// `try_transform_into_with_mode`.
+pub fn transform_into_with_mode<T>(input: &[T], output: &mut [T], operation: Operation,
+                                   wavelet: &Wavelet<T>, level: usize, mode: Mode)
+    where T: Float
+{
+    let result = try_transform_into_with_mode(input, output, operation, wavelet, level, mode);
+    if let Err(error) = result {
+        panic!("{}", error);
+    }
+}
+
+/// Perform the transform out of place and return the output.
+///
+/// The function panics if the transform is not possible; see
+/// `try_transform_into`.
+pub fn transform_to_vec<T>(input: &[T], operation: Operation, wavelet: &Wavelet<T>,
+                           level: usize) -> Vec<T>
+    where T: Float
+{
+    let mut output = vec![T::zero(); input.len()];
+    transform_into(input, &mut output, operation, wavelet, level);
+    output
+}
+
+/// Perform the transform out of place if possible.
+#[inline(always)]
+pub fn try_transform_into<T>(input: &[T], output: &mut [T], operation: Operation,
+                             wavelet: &Wavelet<T>, level: usize) -> Result<()>
+    where T: Float
+{
+    try_transform_into_with_mode(input, output, operation, wavelet, level, Mode::Periodization)
+}
+
+/// Perform the transform out of place with a signal extension mode if
+/// possible.
+///
+/// The first level is computed directly from the input to the output, and the
+/// other ones need a buffer with half as many elements as the input.
+pub fn try_transform_into_with_mode<T>(input: &[T], output: &mut [T], operation: Operation,
+                                       wavelet: &Wavelet<T>, level: usize,
+                                       mode: Mode) -> Result<()>
+    where T: Float
+{
+    let n = input.len();
+    if output.len() != n {
+        return Err(Error::InvalidBufferSize { expected: n, found: output.len() });
+    }
+    if level == 0 {
+        output.copy_from_slice(input);
+        return Ok(());
+    }
+    check(n, wavelet, level, mode)?;
+    let m = (n + 1) >> 1;
+    let mut work = vec![T::zero(); workspace_size(m, level - 1, mode)];
+    match operation {
+        Operation::Forward => {
+            decompose_step(input, output, wavelet, mode);
+            run(&mut output[..m], operation, wavelet, level - 1, mode, &mut work);
+        },
+        Operation::Inverse => {
+            let mut approximation = input[..m].to_vec();
+            run(&mut approximation, operation, wavelet, level - 1, mode, &mut work);
+            reconstruct_step(&approximation, &input[m..], output, wavelet, mode);
+        },
+    }
+    Ok(())
+}
+
+/// Compute the size of the workspace needed by the transform.
+///
+/// The size is the one needed by `Transform::transform_with_workspace` for a
+/// number of points, a level, and a mode, and it is the same for all wavelets.
+/// The size is zero if the level is zero or the mode is not supported.
+#[inline]
+pub fn workspace_size(length: usize, level: usize, mode: Mode) -> usize {
+    match (level, mode) {
+        (0, _) => 0,
+        (_, Mode::Periodization) | (_, Mode::WholeSampleSymmetric) => length,
+        _ => 0,
+    }
+}
+
+// Perform the transform assuming that it has been checked. The work buffer
+// should have at least as many elements as the data.
+pub(crate) fn run<T>(data: &mut [T], operation: Operation, wavelet: &Wavelet<T>, level: usize,
+                     mode: Mode, work: &mut [T])
+    where T: Float
+{
+    let n = data.len();
+    match operation {
+        Operation::Forward => {
+            for i in 0..level {
+                forward_step(data, wavelet, halve(n, i), work, mode);
+            }
+        },
+        Operation::Inverse => {
+            for i in 0..level {
+                inverse_step(data, wavelet, halve(n, level - i - 1), work, mode);
+            }
+        },
     }
 }
 
@@ -54,40 +268,178 @@ macro_rules! zero(
 );
 
 #[inline(always)]
-pub fn forward_step<T>(data: &mut [T], wavelet: &Wavelet<T>, n: usize, work: &mut [T])
+pub fn forward_step<T>(data: &mut [T], wavelet: &Wavelet<T>, n: usize, work: &mut [T], mode: Mode)
     where T: Float
 {
-    zero!(work);
-    let nm = wavelet.length * n - wavelet.offset;
-    let nh = n >> 1;
-    for i in 0..nh {
-        let (mut h, mut g) = (T::zero(), T::zero());
-        let k = 2 * i + nm;
-        for j in 0..wavelet.length {
-            let k = (k + j) % n;
-            h = h + wavelet.dec_lo[j] * data[k];
-            g = g + wavelet.dec_hi[j] * data[k];
-        }
-        work[i] = work[i] + h;
-        work[i + nh] = work[i + nh] + g;
+    decompose_step(data, &mut work[..n], wavelet, mode);
+    copy!(work, data, n);
+}
+
+#[inline(always)]
+pub fn inverse_step<T>(data: &mut [T], wavelet: &Wavelet<T>, n: usize, work: &mut [T], mode: Mode)
+    where T: Float
+{
+    {
+        let (approximation, detail) = data[..n].split_at((n + 1) >> 1);
+        reconstruct_step(approximation, detail, &mut work[..n], wavelet, mode);
     }
     copy!(work, data, n);
 }
 
+// Compute the approximation and detail coefficients of the first points of the
+// data, which are as many as the coefficients, one level down.
+#[inline(always)]
+fn decompose_step<T>(data: &[T], coefficients: &mut [T], wavelet: &Wavelet<T>, mode: Mode)
+    where T: Float
+{
+    let n = coefficients.len();
+    let (approximation, detail) = coefficients.split_at_mut((n + 1) >> 1);
+    for (i, value) in approximation.iter_mut().enumerate() {
+        *value = analyze(data, &wavelet.dec_lo, n, 2 * i, mode);
+    }
+    for (i, value) in detail.iter_mut().enumerate() {
+        *value = analyze(data, &wavelet.dec_hi, n, 2 * i, mode);
+    }
+}
+
+// Compute the points from the approximation and detail coefficients one level
+// down.
 #[inline(always)]
-pub fn inverse_step<T>(data: &mut [T], wavelet: &Wavelet<T>, n: usize, work: &mut [T])
+fn reconstruct_step<T>(approximation: &[T], detail: &[T], data: &mut [T], wavelet: &Wavelet<T>,
+                       mode: Mode)
     where T: Float
 {
-    zero!(work);
-    let nm = wavelet.length * n - wavelet.offset;
-    let nh = n >> 1;
-    for i in 0..nh {
-        let (h, g) = (data[i], data[i + nh]);
-        let k = 2 * i + nm;
-        for j in 0..wavelet.length {
-            let k = (k + j) % n;
-            work[k] = work[k] + wavelet.rec_lo[j] * h + wavelet.rec_hi[j] * g;
+    let n = data.len();
+    if let Mode::WholeSampleSymmetric = mode {
+        for (k, value) in data.iter_mut().enumerate() {
+            *value = gather(approximation, &wavelet.rec_lo, n, k, 0) +
+                     gather(detail, &wavelet.rec_hi, n, k, 1);
+        }
+    } else {
+        zero!(data);
+        for (i, (&approximation, &detail)) in approximation.iter().zip(detail).enumerate() {
+            synthesize(data, &wavelet.rec_lo, n, 2 * i, approximation);
+            synthesize(data, &wavelet.rec_hi, n, 2 * i, detail);
         }
     }
-    copy!(work, data, n);
+}
+
+#[inline(always)]
+fn analyze<T>(data: &[T], filter: &Filter<T>, n: usize, i: usize, mode: Mode) -> T where T: Float {
+    let mut sum = T::zero();
+    if let Mode::WholeSampleSymmetric = mode {
+        let k = i as isize - filter.offset as isize;
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            sum = sum + coefficient * data[reflect(k + j as isize, n)];
+        }
+    } else {
+        let k = i + filter.len() * n - filter.offset;
+        for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+            sum = sum + coefficient * data[(k + j) % n];
+        }
+    }
+    sum
+}
+
+#[inline(always)]
+fn synthesize<T>(data: &mut [T], filter: &Filter<T>, n: usize, i: usize, value: T) where T: Float {
+    let k = i + filter.len() * n - filter.offset;
+    for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+        let k = (k + j) % n;
+        data[k] = data[k] + coefficient * value;
+    }
+}
+
+// Compute the contribution of the coefficients of one band to sample `k`. The
+// approximation and detail coefficients are interleaved at the even and odd
+// samples, respectively, and the interleaved sequence is extended in the
+// whole-sample symmetric way, which is the way the signal is extended and which
+// preserves the parity of the samples given at least two of them.
+#[inline(always)]
+fn gather<T>(data: &[T], filter: &Filter<T>, n: usize, k: usize, band: usize) -> T
+    where T: Float
+{
+    let mut sum = T::zero();
+    for (j, &coefficient) in filter.coefficients.iter().enumerate() {
+        let m = k as isize + filter.offset as isize - j as isize;
+        if m % 2 != 0 {
+            continue;
+        }
+        let m = reflect(m + band as isize, n);
+        sum = sum + coefficient * data[m / 2];
+    }
+    sum
+}
+
+// Check that the transform is possible for a positive level.
+pub(crate) fn check<T>(n: usize, wavelet: &Wavelet<T>, level: usize, mode: Mode) -> Result<()>
+    where T: Float
+{
+    if !wavelet.is_well_formed() {
+        return Err(Error::InvalidWavelet);
+    }
+    match mode {
+        Mode::Periodization => {
+            if level >= usize::BITS as usize || n >> level == 0 {
+                return Err(Error::LevelTooDeep(level));
+            }
+            if n & ((1 << level) - 1) != 0 {
+                return Err(Error::InvalidLength(n));
+            }
+        },
+        Mode::WholeSampleSymmetric => {
+            if !is_whole_sample_symmetric(wavelet) {
+                return Err(Error::InvalidWavelet);
+            }
+            check_whole_sample(n, level)?;
+        },
+        _ => return Err(Error::UnsupportedMode(mode)),
+    }
+    Ok(())
+}
+
+// Check that the number of points allows the whole-sample symmetric transform
+// at a positive level, which requires at least two points at each level but the
+// deepest one.
+pub(crate) fn check_whole_sample(n: usize, level: usize) -> Result<()> {
+    if n == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    if level > usize::BITS as usize || (n - 1) >> (level - 1) == 0 {
+        return Err(Error::LevelTooDeep(level));
+    }
+    Ok(())
+}
+
+// Return the number of points at a level with the approximation band being the
+// larger half at each level.
+#[inline(always)]
+pub(crate) fn halve(n: usize, level: usize) -> usize {
+    (n + (1 << level) - 1) >> level
+}
+
+// Return the index of sample `i` of a signal with `n` samples extended in the
+// whole-sample symmetric way.
+#[inline(always)]
+pub(crate) fn reflect(i: isize, n: usize) -> usize {
+    if n == 1 {
+        return 0;
+    }
+    let period = 2 * (n as isize - 1);
+    let i = i.rem_euclid(period);
+    (if i < n as isize { i } else { period - i }) as usize
+}
+
+fn is_whole_sample_symmetric<T>(wavelet: &Wavelet<T>) -> bool where T: Float {
+    let filters = [
+        (&wavelet.dec_lo, 0), (&wavelet.dec_hi, 1), (&wavelet.rec_lo, 0), (&wavelet.rec_hi, 1),
+    ];
+    filters.iter().all(|&(filter, center)| {
+        let length = filter.len();
+        let scale = filter.coefficients.iter().fold(T::zero(), |scale, value| scale.max(value.abs()));
+        let tolerance = T::epsilon().sqrt() * scale;
+        length % 2 == 1 && filter.offset + center == length / 2 && (0..(length / 2)).all(|j| {
+            (filter.coefficients[j] - filter.coefficients[length - 1 - j]).abs() <= tolerance
+        })
+    })
 }
@@ -0,0 +1,234 @@
+use num::Float;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use wavelet::{Family, Wavelet, biorthogonal};
+
+/// A biorthogonal wavelet.
+pub struct Biorthogonal;
+
+/// A reverse biorthogonal wavelet.
+pub struct ReverseBiorthogonal;
+
+impl Biorthogonal {
+    /// Create a wavelet.
+    ///
+    /// The wavelet known as `biorNr.Nd` is created by `new(Nr, Nd)`, where `Nr`
+    /// and `Nd` are the orders of the reconstruction and decomposition
+    /// filters, respectively. The supported wavelets are 1.1, 1.3, 1.5, 2.2,
+    /// 2.4, 2.6, 2.8, 3.1, 3.3, 3.5, 3.7, 3.9, 4.4, 5.5, and 6.8. The
+    /// decomposition and reconstruction filters generally differ in length.
+    pub fn new<T>(reconstruction: usize, decomposition: usize) -> Wavelet<T> where T: Float {
+        let &(_, _, dec_lo, rec_lo) = FILTERS.iter().find(|&&(order, other, _, _)| {
+            order == reconstruction && other == decomposition
+        }).expect("the wavelet should be supported");
+        let name = format!("bior{}.{}", reconstruction, decomposition);
+        let (moments, _) = moments(reconstruction, decomposition);
+        biorthogonal(dec_lo, rec_lo, dec_lo.len() / 2 - 1)
+            .describe(Family::Biorthogonal, name, moments, 0)
+    }
+}
+
+impl ReverseBiorthogonal {
+    /// Create a wavelet.
+    ///
+    /// The wavelet known as `rbioNr.Nd` is created by `new(Nr, Nd)` and is the
+    /// wavelet created by `Biorthogonal::new(Nr, Nd)` with the decomposition
+    /// and reconstruction filters swapped.
+    pub fn new<T>(reconstruction: usize, decomposition: usize) -> Wavelet<T> where T: Float {
+        let wavelet = Biorthogonal::new(reconstruction, decomposition);
+        let name = format!("rbio{}.{}", reconstruction, decomposition);
+        let (_, moments) = moments(reconstruction, decomposition);
+        Wavelet {
+            dec_lo: wavelet.rec_lo,
+            dec_hi: wavelet.rec_hi,
+            rec_lo: wavelet.dec_lo,
+            rec_hi: wavelet.dec_hi,
+            properties: None,
+        }.describe(Family::ReverseBiorthogonal, name, moments, 0)
+    }
+}
+
+// The numbers of vanishing moments of the decomposition and reconstruction
+// wavelet functions are the numbers of zeros at the Nyquist frequency of the
+// reconstruction and decomposition low-pass filters, respectively. For 5.5,
+// the factorization gives six and four zeros instead of five and five.
+fn moments(reconstruction: usize, decomposition: usize) -> (usize, usize) {
+    match (reconstruction, decomposition) {
+        (5, 5) => (6, 4),
+        _ => (reconstruction, decomposition),
+    }
+}
+
+// The spline wavelets are constructed by Cohen, Daubechies, and Feauveau. The
+// reconstruction low-pass filter of such a wavelet is a B-spline, and the
+// decomposition one is obtained from the binomial formula of Daubechies. For
+// 4.4, 5.5, and 6.8, the binomial polynomial is factored between the two
+// filters, which makes their lengths close to each other. The decomposition
+// and reconstruction low-pass filters are given in this order.
+pub(super) const FILTERS: &[(usize, usize, &[f64], &[f64])] = &[
+    (1, 1, &[
+        FRAC_1_SQRT_2, FRAC_1_SQRT_2,
+    ], &[
+        FRAC_1_SQRT_2, FRAC_1_SQRT_2,
+    ]),
+    (1, 3, &[
+        -0.08838834764831845, 0.08838834764831845, FRAC_1_SQRT_2,
+        FRAC_1_SQRT_2, 0.08838834764831845, -0.08838834764831845,
+    ], &[
+        0.0, 0.0, FRAC_1_SQRT_2,
+        FRAC_1_SQRT_2, 0.0, 0.0,
+    ]),
+    (1, 5, &[
+        0.016572815184059706, -0.016572815184059706, -0.12153397801643785,
+        0.12153397801643785, FRAC_1_SQRT_2, FRAC_1_SQRT_2,
+        0.12153397801643785, -0.12153397801643785, -0.016572815184059706,
+        0.016572815184059706,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2,
+        0.0, 0.0, 0.0,
+        0.0,
+    ]),
+    (2, 2, &[
+        -0.1767766952966369, 0.3535533905932738, 1.0606601717798212,
+        0.3535533905932738, -0.1767766952966369, 0.0,
+    ], &[
+        0.0, 0.3535533905932738, FRAC_1_SQRT_2,
+        0.3535533905932738, 0.0, 0.0,
+    ]),
+    (2, 4, &[
+        0.03314563036811941, -0.06629126073623882, -0.1767766952966369,
+        0.4198446513295126, 0.9943689110435825, 0.4198446513295126,
+        -0.1767766952966369, -0.06629126073623882, 0.03314563036811941,
+        0.0,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.3535533905932738, FRAC_1_SQRT_2, 0.3535533905932738,
+        0.0, 0.0, 0.0,
+        0.0,
+    ]),
+    (2, 6, &[
+        -0.006905339660024878, 0.013810679320049757, 0.04695630968816917,
+        -0.1077232986963881, -0.16987135563661201, 0.4474660099696121,
+        0.966747552403483, 0.4474660099696121, -0.16987135563661201,
+        -0.1077232986963881, 0.04695630968816917, 0.013810679320049757,
+        -0.006905339660024878, 0.0,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.3535533905932738,
+        FRAC_1_SQRT_2, 0.3535533905932738, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0,
+    ]),
+    (2, 8, &[
+        0.0015105430506304422, -0.0030210861012608843, -0.012947511862546647,
+        0.02891610982635418, 0.05299848189069094, -0.13491307360773605,
+        -0.16382918343409023, 0.46257144047591653, 0.9516421218971786,
+        0.46257144047591653, -0.16382918343409023, -0.13491307360773605,
+        0.05299848189069094, 0.02891610982635418, -0.012947511862546647,
+        -0.0030210861012608843, 0.0015105430506304422, 0.0,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.3535533905932738, FRAC_1_SQRT_2,
+        0.3535533905932738, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+    ]),
+    (3, 1, &[
+        -0.3535533905932738, 1.0606601717798212, 1.0606601717798212,
+        -0.3535533905932738,
+    ], &[
+        0.1767766952966369, 0.5303300858899106, 0.5303300858899106,
+        0.1767766952966369,
+    ]),
+    (3, 3, &[
+        0.06629126073623882, -0.1988737822087165, -0.15467960838455727,
+        0.9943689110435825, 0.9943689110435825, -0.15467960838455727,
+        -0.1988737822087165, 0.06629126073623882,
+    ], &[
+        0.0, 0.0, 0.1767766952966369,
+        0.5303300858899106, 0.5303300858899106, 0.1767766952966369,
+        0.0, 0.0,
+    ]),
+    (3, 5, &[
+        -0.013810679320049757, 0.04143203796014927, 0.052480581416189075,
+        -0.26792717880896527, -0.07181553246425873, 0.966747552403483,
+        0.966747552403483, -0.07181553246425873, -0.26792717880896527,
+        0.052480581416189075, 0.04143203796014927, -0.013810679320049757,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, 0.1767766952966369, 0.5303300858899106,
+        0.5303300858899106, 0.1767766952966369, 0.0,
+        0.0, 0.0, 0.0,
+    ]),
+    (3, 7, &[
+        0.0030210861012608843, -0.009063258303782653, -0.01683176542131064,
+        0.074663985074019, 0.03133297870736289, -0.301159125922835,
+        -0.02649924094534547, 0.9516421218971786, 0.9516421218971786,
+        -0.02649924094534547, -0.301159125922835, 0.03133297870736289,
+        0.074663985074019, -0.01683176542131064, -0.009063258303782653,
+        0.0030210861012608843,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.1767766952966369, 0.5303300858899106, 0.5303300858899106,
+        0.1767766952966369, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0,
+    ]),
+    (3, 9, &[
+        -0.0006797443727836989, 0.002039233118351097, 0.005060319219611981,
+        -0.020618912641105536, -0.014112787930175844, 0.09913478249423216,
+        0.012300136269419315, -0.32019196836077857, 0.0020500227115698858,
+        0.9421257006782068, 0.9421257006782068, 0.0020500227115698858,
+        -0.32019196836077857, 0.012300136269419315, 0.09913478249423216,
+        -0.014112787930175844, -0.020618912641105536, 0.005060319219611981,
+        0.002039233118351097, -0.0006797443727836989,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.1767766952966369,
+        0.5303300858899106, 0.5303300858899106, 0.1767766952966369,
+        0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+        0.0, 0.0,
+    ]),
+    (4, 4, &[
+        0.03782845550699546, -0.02384946501938, -0.1106244044184234,
+        0.37740285561265374, 0.8526986790094034, 0.37740285561265374,
+        -0.1106244044184234, -0.02384946501938, 0.03782845550699546,
+        0.0,
+    ], &[
+        0.0, -0.06453888262893843, -0.04068941760955844,
+        0.4180922732222122, 0.7884856164056644, 0.4180922732222122,
+        -0.04068941760955844, -0.06453888262893843, 0.0,
+        0.0,
+    ]),
+    (5, 5, &[
+        0.0, 0.03968708834786253, 0.007948108637387725,
+        -0.054463788468356235, 0.345605281955886, 0.736660181427535,
+        0.345605281955886, -0.054463788468356235, 0.007948108637387725,
+        0.03968708834786253, 0.0, 0.0,
+    ], &[
+        0.013456709459419522, -0.0026949668806074004, -0.13670658466396474,
+        -0.09350469740066429, 0.47680326579781895, 0.899506109749091,
+        0.47680326579781895, -0.09350469740066429, -0.13670658466396474,
+        -0.0026949668806074004, 0.013456709459419522, 0.0,
+    ]),
+    (6, 8, &[
+        0.0019088317364850261, -0.0019142861290808862, -0.0169906398676071,
+        0.01193456527972673, 0.049732903490937654, -0.07726317316721135,
+        -0.09405920349576163, 0.42079628460983926, 0.8259229974584397,
+        0.42079628460983926, -0.09405920349576163, -0.07726317316721135,
+        0.049732903490937654, 0.01193456527972673, -0.0169906398676071,
+        -0.0019142861290808862, 0.0019088317364850261, 0.0,
+    ], &[
+        0.0, 0.0, 0.0,
+        0.014426282505622248, 0.014467504896774099, -0.07872200106266872,
+        -0.040367979030381904, 0.41784910915032025, 0.7589077294537632,
+        0.41784910915032025, -0.040367979030381904, -0.07872200106266872,
+        0.014467504896774099, 0.014426282505622248, 0.0,
+        0.0, 0.0, 0.0,
+    ]),
+];
@@ -0,0 +1,367 @@
+use num::Float;
+
+use wavelet::{Family, Wavelet, orthogonal};
+
+/// A coiflet.
+pub struct Coiflets;
+
+impl Coiflets {
+    /// Create a wavelet.
+    ///
+    /// The order should be between 1 and 17. The wavelet of order `order` has
+    /// `2 * order` vanishing moments and `6 * order` coefficients.
+    pub fn new<T>(order: usize) -> Wavelet<T> where T: Float {
+        assert!(order > 0 && order <= COEFFICIENTS.len());
+        orthogonal(COEFFICIENTS[order - 1], 2 * order)
+            .describe(Family::Coiflets, format!("coif{}", order), 2 * order, 2 * order - 1)
+    }
+}
+
+// The coefficients of the scaling filters are computed by solving the
+// orthogonality conditions in the parametrization given by Daubechies, starting
+// from the interpolating filter of Deslauriers and Dubuc. The moments of the
+// scaling function vanish around coefficient `2 * order`, which is where the
+// filters are centered so that the approximation coefficients stay aligned with
+// the samples of the signal.
+pub(super) const COEFFICIENTS: &[&[f64]] = &[
+    &[
+        -0.07273261951252645, 0.33789766245748176, 0.8525720202116004,
+        0.3848648468648577, -0.07273261951252645, -0.015655728135791993,
+    ],
+    &[
+        0.01638733646320364, -0.04146493678687178, -0.0673725547237256,
+        0.38611006682276283, 0.8127236354494135, 0.41700518442323903,
+        -0.07648859907828076, -0.059434418646431085, 0.02368017194684777,
+        0.005611434819368834, -0.001823208870911032, -0.000720549445520347,
+    ],
+    &[
+        -0.0037935128643808015, 0.0077825964256727454, 0.023452696142077165,
+        -0.06577191128146936, -0.06112339000297254, 0.4051769024091182,
+        0.7937772226260872, 0.42848347637737, -0.07179982161915484,
+        -0.08230192710629981, 0.03455502757329773, 0.015880544863669452,
+        -0.009007976136730624, -0.002574517688136797, 0.0011175187708306303,
+        0.0004662169598204029, -7.0983302506379e-05, -3.4599773197272774e-05,
+    ],
+    &[
+        0.000892313902537003, -0.0016294924252267858, -0.00734616793626805,
+        0.016068947131575025, 0.026682304669604834, -0.08126671024919373,
+        -0.05607731960356926, 0.41530842700068227, 0.7822389344242826,
+        0.43438603311435653, -0.06662747236681715, -0.09622042453595264,
+        0.03933442260558915, 0.025082253337949608, -0.015211728187697211,
+        -0.0056582838001308835, 0.003751434697146086, 0.0012665610789256603,
+        -0.0005890202246332164, -0.0002599743371222568, 6.233885431278718e-05,
+        3.1229861599195265e-05, -3.2596479400307506e-06, -1.7849909144933466e-06,
+    ],
+    &[
+        -0.000212081862067494, 0.0003585777411617577, 0.0021782943778456947,
+        -0.004159312627578639, -0.010131584846900275, 0.023408322118927783,
+        0.028169744270532353, -0.09192158806008609, -0.05204667025355476,
+        0.42157126673075435, 0.7742936228603274, 0.4379823066591633,
+        -0.06203775157498195, -0.10556315130733723, 0.041287530472117834,
+        0.03267479946705735, -0.019758391600965465, -0.009159507338676163,
+        0.006761520220620417, 0.0024315754425382886, -0.0016616273039298788,
+        -0.0006375589261258812, 0.00030185794166824473, 0.00014035632812373243,
+        -4.12198619242655e-05, -2.1270221672515614e-05, 3.7007277113394796e-06,
+        2.0612203985788783e-06, -1.6237995172048335e-07, -9.604010112767892e-08,
+    ],
+    &[
+        5.0775487836340565e-05, -8.11700262678484e-05, -0.0006246130439256835,
+        0.001091624712325903, 0.003539019871540998, -0.007029406391002729,
+        -0.012231577790037912, 0.02964577289132384, 0.02878611434666557,
+        -0.09967300204601175, -0.04876407217567387, 0.4258195450128385,
+        0.7684032575798924, 0.4404011911268528, -0.0581089179726148,
+        -0.11226080796481723, 0.04185249067613627, 0.03888132625151076,
+        -0.022950153279849065, -0.01265006790873235, 0.009591090175904052,
+        0.0038576582705936867, -0.003073939507208559, -0.0011574350134273346,
+        0.0007698547307507267, 0.00032522235901024076, -0.0001545771992797995,
+        -7.528004306935964e-05, 2.473655932872323e-05, 1.313985135402144e-05,
+        -2.924385559757523e-06, -1.6596192951024209e-06, 2.255997852816182e-07,
+        1.3503244993561446e-07, -8.487143396262437e-09, -5.309088417196893e-09,
+    ],
+    &[
+        -1.2222250624065772e-05, 1.871135500141218e-05, 0.00017510216778483177,
+        -0.0002872023753570612, -0.0011693144285797633, 0.002105772041410548,
+        0.004829446560702038, -0.00993889526908058, -0.0138025542362884,
+        0.03491050510474272, 0.028937041983523145, -0.10555616822156129,
+        -0.046033397038466296, 0.4288888072494226, 0.7638153654167333,
+        0.44213746140184257, -0.054751241648150456, -0.1172935710431928,
+        0.04170535760257679, 0.04399304616307942, -0.025154257568539024,
+        -0.01594684681956794, 0.012052338241841622, 0.005431316442880095,
+        -0.004617842130433119, -0.0018015372833330425, 0.0014347418566524122,
+        0.0005794994482340953, -0.00036906682873489536, -0.00016781721215484971,
+        7.971050025993867e-05, 4.04304824171402e-05, -1.4235636978451501e-05,
+        -7.771243547311862e-06, 2.0020780498554183e-06, 1.1579769069489573e-06,
+        -2.0693205243938526e-07, -1.255091319079457e-07, 1.3935103885216451e-08,
+        8.796593384856987e-09, -4.5783340677929505e-10, -2.990566231736866e-10,
+    ],
+    &[
+        2.9543365214148865e-06, -4.368264820320075e-06, -4.829631521409294e-05,
+        7.54736783816504e-05, 0.0003712949956074124, -0.0006235604474579403,
+        -0.001783260008597197, 0.0033008250106161103, 0.005994849192155886,
+        -0.012742370632719796, -0.014978462081708433, 0.03937203787797985,
+        0.028828621759288006, -0.11016997698347016, -0.04371898336594559,
+        0.4312098155550876, 0.7601133020179405, 0.44344254984152603,
+        -0.051860743161188674, -0.12121116823149647, 0.04118580667625654,
+        0.048252371085682255, -0.026656710542648603, -0.018985244695254866,
+        0.014117470077618781, 0.007065827011035096, -0.0061566595482584205,
+        -0.0025440037102452736, 0.002235649422048103, 0.0008967760630796798,
+        -0.0006871716433480045, -0.00029777893219563997, 0.00018169287648431021,
+        8.754452091843062e-05, -4.147478606916181e-05, -2.1802000767010352e-05,
+        8.031502995440787e-06, 4.496936443579391e-06, -1.2754542996407563e-06,
+        -7.515021558886325e-07, 1.5893517221530649e-07, 9.772418508367798e-08,
+        -1.4540008533753526e-08, -9.271205591546297e-09, 8.669995082338711e-10,
+        5.704810333909736e-10, -2.525423493885457e-11, -1.7079895947055483e-11,
+    ],
+    &[
+        -7.164920431247886e-07, 1.0293200668945786e-06, 1.3158885645425329e-05,
+        -1.978720443246248e-05, -0.00011443395278590283, 0.0001822848596634226,
+        0.0006264730321397159, -0.001075458272741238, -0.0024212416736516485,
+        0.004597056424920538, 0.007022340460196237, -0.015376649629718764,
+        -0.015860223894792906, 0.043181727608250446, 0.028572667556949285,
+        -0.11388350819004509, -0.0417261102058528, 0.4330267511031542,
+        0.7570455233843789, 0.44445789317644796, -0.04934886629362917,
+        -0.12434558953929062, 0.04047376745572896, 0.05184461568624731,
+        -0.027661239498680462, -0.021754553510948845, 0.01581871581592506,
+        0.008702757446229182, -0.007614042448258917, -0.0033576745265865788,
+        0.0031132277883843037, 0.0012696909251353398, -0.001095745627952607,
+        -0.0004627290855053043, 0.0003369138692848271, 0.00015541352126673886,
+        -9.135595508746476e-05, -4.613708198462493e-05, 2.1776396410029025e-05,
+        1.1814409451578695e-05, -4.488111475152764e-06, -2.5723835744866874e-06,
+        7.802480329370885e-07, 4.6795847694542985e-07, -1.1096670180879423e-07,
+        -6.916547041218038e-08, 1.2375256619810124e-08, 7.97400588684683e-09,
+        -1.0136275688170466e-09, -6.723464414885984e-10, 5.417100964283038e-11,
+        3.686179736445179e-11, -1.416273550918584e-12, -9.858437261237078e-13,
+    ],
+    &[
+        1.742367480312722e-07, -2.4427648648848456e-07, -3.551205538569571e-06,
+        5.173962608452715e-06, 3.4459693234170226e-05, -5.264472185921727e-05,
+        -0.00021177413649420268, 0.00034345502618015684, 0.0009249399604237314,
+        -0.0016207781088532927, -0.0030539924938115656, 0.0059373732658958775,
+        0.007917157067706416, -0.017820445781285544, -0.016521511268705533,
+        0.04646274705470044, 0.028232912738798775, -0.11693607050206899,
+        -0.03998711301587223, 0.43448818216271134, 0.7544501094947819,
+        0.44526919771961493, -0.047145262538020274, -0.12690910430554905,
+        0.039668349279538065, 0.05490896399592107, -0.028310063944428573,
+        -0.02426732868279509, 0.01720591249831959, 0.010305378002449852,
+        -0.008953207286543774, -0.004218113884810503, 0.004020222790700274,
+        0.0016899697926397444, -0.0015748582231923615, -0.0006598662532419505,
+        0.0005451673708605961, 0.00024363173072084558, -0.00016857983433223972,
+        -8.202162255997293e-05, 4.6724981354812776e-05, 2.4541910121029197e-05,
+        -1.153105839215023e-05, -6.43432948907388e-06, 2.4972027910054274e-06,
+        1.4624450319782122e-06, -4.657624401004923e-07, -2.840907583862188e-07,
+        7.315542758722408e-08, 4.62090305730452e-08, -9.39633274741924e-09,
+        -6.118910132543529e-09, 9.4678306369391e-10, 6.333121950019276e-10,
+        -7.01292033330539e-11, -4.804052212478306e-11, 3.3934647379161654e-12,
+        2.374617931225516e-12, -8.04450859948987e-14, -5.737961266897435e-14,
+    ],
+    &[
+        -4.246658850505321e-08, 5.829773387691353e-08, 9.510573210512222e-07,
+        -1.3493521791802846e-06, -1.0185919231767138e-05, 1.503949885335962e-05,
+        6.942759574780768e-05, -0.00010766668137712067, -0.00033810804201454006,
+        0.0005577204869484765, 0.0012520730398850818, -0.002236842519594617,
+        -0.0036638635291587457, 0.007283017155944006, 0.008691538302863682,
+        -0.020073251976164122, -0.017015922424480565, 0.049312538937724984,
+        0.02784705124131645, -0.11948939622817124, -0.03845246068584405,
+        0.4356896083515125, 0.7522175440402373, 0.4459314428205927,
+        -0.04519495629357892, -0.12904373222526422, 0.038825603724123164,
+        0.0575502447040702, -0.028702596019727925, -0.026545070862175352,
+        0.018329643336621633, 0.011851974427506963, -0.010160884866512015,
+        -0.005105112841283235, 0.004922673261101512, 0.002149020717634627,
+        -0.0021036100764663637, -0.0008861775180562737, 0.0008017452103384917,
+        0.0003512267504200824, -0.0002754268778467295, -0.0001298668208927486,
+        8.582415436286544e-05, 4.3683643044787776e-05, -2.423559202462401e-05,
+        -1.3163750930799775e-05, 6.153164872698853e-06, 3.5211459772931156e-06,
+        -1.3873754845796893e-06, -8.287967353524098e-07, 2.7376933637000196e-07,
+        1.6966516600849333e-07, -4.6475516545582084e-08, -2.9737144543121354e-08,
+        6.64300428484731e-09, 4.371514216501341e-09, -7.7681779289702e-10,
+        -5.240838827477836e-10, 7.131883881772344e-11, 4.919793979271459e-11,
+        -4.8184231146019004e-12, -3.391025392797988e-12, 2.1292784594645594e-13,
+        1.5258255495330549e-13, -4.615818040032401e-15, -3.3623613180937427e-15,
+    ],
+    &[
+        1.0370100091015128e-08, -1.3976374181436276e-08, -2.531117984656268e-07,
+        3.5104114178200534e-07, 2.9654119498787756e-06, -4.255365106601993e-06,
+        -2.2199299361439428e-05, 3.3195586707854686e-05, 0.00011925526520634741,
+        -0.00018759057842499834, -0.0004894690765309505, 0.0008204877603314603,
+        0.0015956995222675776, -0.0029033496249956676, -0.004241021014921407,
+        0.008608649814937596, 0.009359630574317223, -0.022144389024790892,
+        -0.01738271453899042, 0.05180772024804606, 0.02743798196811415,
+        -0.12165660970268916, -0.03708505815411896, 0.4366951744905069,
+        0.7502707776506686, 0.4464815228895739, -0.04345493993503662,
+        -0.13084806656615866, 0.037977672565709164, 0.05984819686064441,
+        -0.028908947441411717, -0.02861160212351853, 0.01923522882933757,
+        0.013330755435505158, -0.011236928414836344, -0.006002793708808538,
+        0.005797853171804963, 0.002638532076072489, -0.002663098695894828,
+        -0.0011384852551442936, 0.001099444238862774, 0.000476992733288641,
+        -0.0004119898713595868, -0.00018957894275957224, 0.0001411686033736131,
+        6.982465534338538e-05, -4.4336114427463846e-05, -2.3449750697009528e-05,
+        1.2718544951144566e-05, 7.112977349641407e-06, -3.3062392128971758e-06,
+        -1.935601054214237e-06, 7.708437343672786e-07, 4.6902731107106103e-07,
+        -1.5931230530960278e-07, -1.0022581131987927e-07, 2.8795319269859757e-08,
+        1.865413770679035e-08, -4.4775234981507144e-09, -2.976907032226953e-09,
+        5.864666471845081e-10, 3.990594029041717e-10, -6.288984311455846e-11,
+        -4.3693893072214714e-11, 5.299712095238988e-12, 3.7520235203128594e-12,
+        -3.290045600002305e-13, -2.3693808823010433e-13, 1.3374548129663103e-14,
+        9.783102459953237e-15, -2.6703616233325654e-16, -1.9813377170414736e-16,
+    ],
+    &[
+        -2.536460462157101e-09, 3.3632985120346297e-09, 6.701078769903018e-08,
+        -9.111838156033149e-08, -8.52440589065104e-07, 1.1938880784407086e-06,
+        6.952301751263799e-06, -1.008560197073056e-05, -4.0837137965758754e-05,
+        6.181045384455713e-05, 0.00018399068204652167, -0.00029338106939892823,
+        -0.000661238086717906, 0.001125533913083125, 0.001946295412387387,
+        -0.0036033112787683154, -0.004780675949334216, 0.00989833068184399,
+        0.009935243248428365, -0.02404734005036483, -0.017650941474490738,
+        0.05400870551305577, 0.027019867059454368, -0.1235191563398333,
+        -0.03585654727086103, 0.4375495433141353, 0.7485538324581938,
+        0.44694511891208283, -0.041891314001197784, -0.13239268372862886,
+        0.03714297920870841, 0.06186422181011112, -0.02897913316298715,
+        -0.030490095794234087, 0.01996119614595352, 0.014736317631638981,
+        -0.012188034671027212, -0.006899115322911729, 0.006631574528454967,
+        0.003150877638188897, -0.003237559413824625, -0.0014135265368952603,
+        0.0014301051868561214, 0.0006196153396181822, -0.0005766881259563565,
+        -0.00026078674317641943, 0.00021391688899529058, 0.00010322189107027523,
+        -7.329758910003689e-05, -3.7799662099993e-05, 2.318774250938065e-05,
+        1.2676538815653462e-05, -6.740799218474297e-06, -3.868533337330695e-06,
+        1.7875606278098432e-06, 1.068427617719579e-06, -4.2870074334562694e-07,
+        -2.653281517709694e-07, 9.209610667203677e-08, 5.8765319522698225e-08,
+        -1.7530546273017996e-08, -1.1492127566309323e-08, 2.9186399704548123e-09,
+        1.959961326135216e-09, -4.18214654071213e-10, -2.8698804595086623e-10,
+        5.051249576886846e-11, 3.534700054054495e-11, -4.99908968900025e-12,
+        -3.5608264474554283e-12, 3.8915790350685617e-13, 2.817158912020827e-13,
+        -2.23397799523526e-14, -1.6413244059647732e-14, 8.406602973450897e-16,
+        6.260971679551619e-16, -1.5554270417986292e-17, -1.1730386640303017e-17,
+    ],
+    &[
+        6.212814528402405e-10, -8.118993462836169e-10, -1.7662495148738494e-08,
+        2.360235756379167e-08, 2.424279845810464e-07, -3.324658385771607e-07,
+        -2.139463925057922e-06, 3.0247808186844957e-06, 1.3640357675528054e-05,
+        -1.9995576397409376e-05, -6.692604993754285e-05, 0.00010248333219568597,
+        0.00026286235206499846, -0.0004246373286971684, -0.0008488083847885694,
+        0.0014661214569906414, 0.0022967107264584086, -0.004323062280774556,
+        -0.00528120283630856, 0.01114255210951469, 0.010430949170645137,
+        -0.025796843770827978, -0.017842354152438636, 0.05596345341137635,
+        0.026601546271988057, -0.12513713600885462, -0.03474484970544801,
+        0.4382847599501609, 0.7470249379615077, 0.4473406567537405,
+        -0.04047709315229174, -0.13372942834321536, 0.03633187623818002,
+        0.063646203141354, -0.028949256131946835, -0.03220185256406462,
+        0.02053949987453051, 0.016067283926065302, -0.013024088005389463,
+        -0.0077852024170722255, 0.0074157782307655, 0.0036793235019773754,
+        -0.0038145240765801707, -0.0017080538661070365, 0.0017856181989036103,
+        0.0007777201566101955, -0.0007668251488526075, -0.0003429728615062403,
+        0.0003043599006063343, 0.00014390413588042603, -0.00011221961943808773,
+        -5.6544184041209396e-05, 3.849201590195014e-05, 2.0583278936576757e-05,
+        -1.2254515725465707e-05, -6.89570939784008e-06, 3.6028936013066516e-06,
+        2.1160757624955858e-06, -9.71842353346463e-07, -5.919974641332917e-07,
+        2.387649405196465e-07, 1.501341371314425e-07, -5.300650696936341e-08,
+        -3.427757286145719e-08, 1.0538640451894265e-08, 6.987387541011994e-09,
+        -1.8568684752939706e-09, -1.2590752100024662e-09, 2.862848247030911e-10,
+        1.980912034671011e-10, -3.801184928644451e-11, -2.6789821104151334e-11,
+        4.2574178967331865e-12, 3.051235212860773e-12, -3.910486063605146e-13,
+        -2.8459140168757134e-13, 2.8278043784770036e-14, 2.0871754356845036e-14,
+        -1.5093680534110733e-15, -1.1286067317116846e-15, 5.2862745174656315e-17,
+        4.0004379861267005e-17, -9.112090547755772e-19, -6.972752077994577e-19,
+    ],
+    &[
+        -1.5236571538490175e-10, 1.965173624853245e-10, 4.6377634686116066e-09,
+        -6.102214747289204e-09, -6.831331679744406e-08, 9.197256712539524e-08,
+        6.485869388545793e-07, -8.968212011102487e-07, -4.460423183361215e-06,
+        6.3636840151377605e-06, 2.367321573111412e-05, -3.505374322008593e-05,
+        -0.00010088156289150038, 0.00015621764400384816, 0.0003545813856134761,
+        -0.0005800110441534673, -0.0010479389168413227, 0.00183566044669003,
+        0.002641753814723817, -0.005051893642506076, -0.005742902847255388,
+        0.012336147571654247, 0.010857812184642856, -0.027407439453778118,
+        -0.017973423829255167, 0.057710351175148604, 0.02618853484172406,
+        -0.12655584892864374, -0.0337324960509805, 0.4389244085313621,
+        0.7456522133710781, 0.4476816987616904, -0.03919056961249978,
+        -0.13489723657819958, 0.03554988160862526, 0.0652319448331683,
+        -0.028845673444251017, -0.0337659081361963, 0.020996338546795255,
+        0.01732475364848346, -0.013756093488966996, -0.008654690594816527,
+        0.008146642682593413, 0.004218089500963982, -0.004384524134541961,
+        -0.0020189297967994203, 0.0021584984665309805, 0.0009498999754877983,
+        -0.0009790730546106287, -0.0004355429437926704, 0.0004120686813883099,
+        0.00019175221055863046, -0.00016180532955436763, -7.986582333506879e-05,
+        5.94367722239149e-05, 3.1122483672216425e-05, -2.0413990718732566e-05,
+        -1.1267101774009072e-05, 6.534415586793015e-06, 3.7720910647546907e-06,
+        -1.9398003868320845e-06, -1.1633685314082284e-06, 5.309958252905003e-07,
+        3.2915262541112327e-07, -1.332072673921234e-07, -8.50069822991393e-08,
+        3.0420306905522665e-08, 1.992151339689276e-08, -6.276946965454188e-09,
+        -4.207104779368382e-09, 1.1601835214850224e-09, 7.94067760542442e-10,
+        -1.9012249889043818e-10, -1.3262295239786242e-10, 2.727799122291351e-11,
+        1.9360741815540646e-11, -3.3728071967571495e-12, -2.4321347403077765e-12,
+        3.52051387891066e-13, 2.575890601011181e-13, -3.015982985137597e-14,
+        -2.236551020293379e-14, 2.035879447713056e-15, 1.5285822542874648e-15,
+        -1.015262275777643e-16, -7.710937330347503e-17, 3.3250227224748574e-18,
+        2.5524759205848718e-18, -5.364244753127261e-20, -4.1590573930635623e-20,
+    ],
+    &[
+        3.740776214156404e-11, -4.7676012848234074e-11, -1.2137633499446819e-09,
+        1.574985240567528e-09, 1.909693969280092e-08, -2.529381289790086e-08,
+        -1.940866364398909e-07, 2.63205338677281e-07, 1.4320792047190594e-06,
+        -1.99600860362496e-06, -8.174656304120633e-06, 1.1765624579909586e-05,
+        3.756395791987938e-05, -5.616532904749551e-05, -0.00014276520952053923,
+        0.00022347487351192206, 0.0004575809541850859, -0.0007575611156619532,
+        -0.0012549104014289143, 0.002228062752711592, 0.0029777774831563023,
+        -0.005781576422578688, -0.00616720474022964, 0.013476845139049088,
+        0.01122539739108762, -0.02889277993976114, -0.0180567607738234,
+        0.05928039565969397, 0.02578422990744645, -0.12781007706564043,
+        -0.03280546383358694, 0.43948622906261486, 0.7444108510775943,
+        0.44797844765328076, -0.03801409965109622, -0.13592591498912343,
+        0.034799585149717135, 0.06665164158948773, -0.028687828349824904,
+        -0.0351990273138655, 0.021353089890706896, 0.018511294910050092,
+        -0.014395049063084732, -0.009503158857743535, 0.008823187183975602,
+        0.004762320192500802, -0.004940628195250106, -0.0023432001881844894,
+        0.0025421630161041333, 0.0011347385993702144, -0.0012098453397664975,
+        -0.0005378608496461215, 0.0005360682879309422, 0.00024655483339052153,
+        -0.0002223765664752645, -0.0001078330571203061, 8.66638640700267e-05,
+        4.450165161739413e-05, -3.1753723132732214e-05, -1.7200276038454787e-05,
+        1.0919026051004468e-05, 6.196734772234442e-06, -3.5111629395158586e-06,
+        -2.0737231450435258e-06, 1.0510455241801266e-06, 6.424757126690243e-07,
+        -2.914269718183252e-07, -1.8358908436289854e-07, 7.44524275745434e-08,
+        4.817367733205772e-08, -1.742573716313695e-08, -1.1548600098911635e-08,
+        3.712889030843456e-09, 2.514431389815212e-09, -7.149797543708523e-10,
+        -4.937872384599662e-10, 1.233822423910208e-10, 8.674866101263907e-11,
+        -1.8887542434426147e-11, -1.3498670292821782e-11, 2.533043295168227e-12,
+        1.837741692652944e-12, -2.929603467665681e-13, -2.1550866999641267e-13,
+        2.862384604227304e-14, 2.13276208566395e-14, -2.297130946940807e-15,
+        -1.7320153218245642e-15, 1.4537260798059527e-16, 1.1082430334134504e-16,
+        -6.801835449347436e-18, -5.238852980994671e-18, 2.0917311921863156e-19,
+        1.6265840212247008e-19, -3.1712433739678645e-21, -2.4882348740871053e-21,
+    ],
+    &[
+        -9.193044901647832e-12, 1.1589611003950016e-11, 3.16742992539928e-10,
+        -4.0587190405967056e-10, -5.3013957283181885e-09, 6.919675145357251e-09,
+        5.7424650773311224e-08, -7.654855556872685e-08, -4.5249840101954436e-07,
+        6.179728213804948e-07, 2.764328498891352e-06, -3.882741927783709e-06,
+        -1.3625117490772093e-05, 1.977791990756864e-05, 5.567734714465559e-05,
+        -8.40349922171605e-05, -0.00019235958524774468, 0.0003042648910918758,
+        0.0005701892687802021, -0.0009550264124726987, -0.0014665663886605693,
+        0.0026379020095295557, 0.0033023194534331645, -0.006505894912824299,
+        -0.006556154798272959, 0.014564276648080906, 0.011541898708774485,
+        -0.030265350462571627, -0.018102118385278566, 0.060698836099323736,
+        0.025390658639329412, -0.1289269634440451, -0.03195235252061503,
+        0.439983820652042, 0.743281221441139, 0.4482387248514952,
+        -0.036933200696832556, -0.13683866582966367, 0.034081797453796955,
+        0.0679296726646839, -0.028490198136857305, -0.0365158661868462,
+        0.021627181084123398, 0.019630293958411843, -0.014951364256061295,
+        -0.010327665987284079, 0.009446275522838596, 0.005308007234603321,
+        -0.005477959124841987, -0.0026781423352943918, 0.0029310184543699363,
+        0.0013308341819630047, -0.0014555598050827946, -0.00064926747401087,
+        0.0006750053722876709, 0.0003080445755741306, -0.00029391737297815475,
+        -0.00014043136491916186, 0.0001206354433691317, 6.085540589948546e-05,
+        -4.674968583543716e-05, -2.487360746159996e-05, 1.7094583307705573e-05,
+        9.54094608426775e-06, -5.883630730192029e-06, -3.422757310554498e-06,
+        1.8992806819779943e-06, 1.1451303776922555e-06, -5.726779955346247e-07,
+        -3.562354702980367e-07, 1.6059153270877367e-07, 1.0269526627044103e-07,
+        -4.169073699211024e-08, -2.7327724210567907e-08, 9.970659851204182e-09,
+        6.682924028386818e-09, -2.1848047745514903e-09, -1.4942808790878763e-09,
+        4.359447175469891e-10, 3.037030461263032e-10, -7.864847350619417e-11,
+        -5.572204369998765e-11, 1.2721761153695078e-11, 9.153903474792873e-12,
+        -1.826498790845331e-12, -1.3331324069830058e-12, 2.298842703415546e-13,
+        1.7001567646610813e-13, -2.4968201502492676e-14, -1.8692778101462477e-14,
+        2.292546657107991e-15, 1.7359444437405518e-15, -1.7301946960729855e-16,
+        -1.3240524883566648e-16, 1.0304394817390555e-17, 7.963782939035077e-18,
+        -4.540571956247477e-19, -3.541741324829286e-19, 1.3159795922880465e-20,
+        1.0354104908732473e-20, -1.881677148105947e-22, -1.4925731767051474e-22,
+    ],
+];
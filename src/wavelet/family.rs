@@ -0,0 +1,99 @@
+use super::{biorthogonal, coiflets, daubechies, symlets};
+
+/// A family of wavelets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Family {
+    /// The Haar wavelet.
+    Haar,
+    /// The Daubechies wavelets.
+    Daubechies,
+    /// The symlets.
+    Symlets,
+    /// The coiflets.
+    Coiflets,
+    /// The biorthogonal wavelets.
+    Biorthogonal,
+    /// The reverse biorthogonal wavelets.
+    ReverseBiorthogonal,
+    /// The discrete Meyer wavelet.
+    DiscreteMeyer,
+    /// The Cohen–Daubechies–Feauveau 9/7 wavelet.
+    Cdf97,
+    /// The Le Gall 5/3 wavelet.
+    LeGall53,
+}
+
+const FAMILIES: [Family; 9] = [
+    Family::Haar,
+    Family::Daubechies,
+    Family::Symlets,
+    Family::Coiflets,
+    Family::Biorthogonal,
+    Family::ReverseBiorthogonal,
+    Family::DiscreteMeyer,
+    Family::Cdf97,
+    Family::LeGall53,
+];
+
+impl Family {
+    /// Return all the families.
+    pub fn all() -> &'static [Family] {
+        &FAMILIES
+    }
+
+    /// Return the name.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Family::Haar => "Haar",
+            Family::Daubechies => "Daubechies",
+            Family::Symlets => "Symlets",
+            Family::Coiflets => "Coiflets",
+            Family::Biorthogonal => "Biorthogonal",
+            Family::ReverseBiorthogonal => "Reverse biorthogonal",
+            Family::DiscreteMeyer => "Discrete Meyer",
+            Family::Cdf97 => "Cohen–Daubechies–Feauveau 9/7",
+            Family::LeGall53 => "Le Gall 5/3",
+        }
+    }
+
+    /// Return the short name.
+    ///
+    /// The short name is the prefix of the names of the members.
+    pub fn short_name(&self) -> &'static str {
+        match *self {
+            Family::Haar => "haar",
+            Family::Daubechies => "db",
+            Family::Symlets => "sym",
+            Family::Coiflets => "coif",
+            Family::Biorthogonal => "bior",
+            Family::ReverseBiorthogonal => "rbio",
+            Family::DiscreteMeyer => "dmey",
+            Family::Cdf97 => "cdf97",
+            Family::LeGall53 => "legall53",
+        }
+    }
+
+    /// Return the names of the members.
+    ///
+    /// The names are the ones accepted by `Wavelet::from_name`.
+    pub fn members(&self) -> Vec<String> {
+        let prefix = self.short_name();
+        match *self {
+            Family::Daubechies => {
+                (1..(daubechies::COEFFICIENTS.len() + 1)).map(|i| format!("{}{}", prefix, i)).collect()
+            },
+            Family::Symlets => {
+                (2..(symlets::COEFFICIENTS.len() + 2)).map(|i| format!("{}{}", prefix, i)).collect()
+            },
+            Family::Coiflets => {
+                (1..(coiflets::COEFFICIENTS.len() + 1)).map(|i| format!("{}{}", prefix, i)).collect()
+            },
+            Family::Biorthogonal | Family::ReverseBiorthogonal => {
+                biorthogonal::FILTERS.iter().map(|&(reconstruction, decomposition, _, _)| {
+                    format!("{}{}.{}", prefix, reconstruction, decomposition)
+                }).collect()
+            },
+            _ => vec![prefix.to_string()],
+        }
+    }
+}
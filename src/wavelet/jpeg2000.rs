@@ -0,0 +1,177 @@
+use num::{Float, PrimInt, Signed};
+use std::f64::consts::SQRT_2;
+
+use {Operation, Result};
+use transform::{check_whole_sample, halve, reflect};
+use wavelet::{Family, Filter, Wavelet};
+
+/// A Cohen–Daubechies–Feauveau 9/7 wavelet.
+///
+/// The wavelet is the irreversible wavelet of JPEG 2000.
+pub struct Cdf97;
+
+/// A Le Gall 5/3 wavelet.
+///
+/// The wavelet is the one of the reversible path of JPEG 2000. The filters are
+/// applied as is, without the rounding performed by the integer lifting scheme
+/// of the standard, and hence the transform with them is neither lossless on
+/// integers nor equal to the one of the standard. The reversible transform
+/// itself is given by `LeGall53::transform_reversible`.
+pub struct LeGall53;
+
+/// A normalization of the filters of a JPEG 2000 wavelet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Normalization {
+    /// The normalization of the standard.
+    ///
+    /// The low-pass decomposition filter has gain one at zero frequency, and
+    /// the high-pass decomposition filter has gain two at the Nyquist
+    /// frequency.
+    Standard,
+    /// The normalization in which both decomposition filters have gain √2,
+    /// which is the one of the biorthogonal wavelets 4.4 and 2.2.
+    Balanced,
+}
+
+impl Cdf97 {
+    /// Create a wavelet.
+    pub fn new<T>(normalization: Normalization) -> Wavelet<T> where T: Float {
+        jpeg2000(&CDF97_ANALYSIS, &CDF97_SYNTHESIS, normalization)
+            .describe(Family::Cdf97, "cdf97".to_string(), 4, 0)
+    }
+}
+
+impl LeGall53 {
+    /// Create a wavelet.
+    pub fn new<T>(normalization: Normalization) -> Wavelet<T> where T: Float {
+        jpeg2000(&LEGALL53_ANALYSIS, &LEGALL53_SYNTHESIS, normalization)
+            .describe(Family::LeGall53, "legall53".to_string(), 2, 0)
+    }
+
+    /// Perform the reversible transform of JPEG 2000.
+    ///
+    /// The transform is the integer lifting scheme of the standard with
+    /// rounding down and whole-sample symmetric extension, and it is lossless.
+    /// The signal is assumed to start at an even coordinate. The layout of the
+    /// coefficients and the constraints on the number of points are the ones
+    /// of `Transform::transform_with_mode` with whole-sample symmetric
+    /// extension.
+    ///
+    /// The function panics if the transform is not possible; see
+    /// `try_transform_reversible`.
+    pub fn transform_reversible<T>(data: &mut [T], operation: Operation, level: usize)
+        where T: PrimInt + Signed
+    {
+        if let Err(error) = LeGall53::try_transform_reversible(data, operation, level) {
+            panic!("{}", error);
+        }
+    }
+
+    /// Perform the reversible transform of JPEG 2000 if possible.
+    pub fn try_transform_reversible<T>(data: &mut [T], operation: Operation,
+                                       level: usize) -> Result<()>
+        where T: PrimInt + Signed
+    {
+        if level == 0 {
+            return Ok(());
+        }
+        let n = data.len();
+        check_whole_sample(n, level)?;
+        let mut work = vec![T::zero(); n];
+        match operation {
+            Operation::Forward => {
+                for i in 0..level {
+                    lift_forward(data, halve(n, i), &mut work);
+                }
+            },
+            Operation::Inverse => {
+                for i in (0..level).rev() {
+                    lift_inverse(data, halve(n, i), &mut work);
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+// Perform one level of the forward lifting on the first `n` points. The
+// interleaved sequence is updated in the work buffer, with the odd samples
+// predicted from the even ones and then the even samples updated from the odd
+// ones, and split into the two bands afterwards.
+fn lift_forward<T>(data: &mut [T], n: usize, work: &mut [T]) where T: PrimInt + Signed {
+    let two = T::one() + T::one();
+    work[..n].copy_from_slice(&data[..n]);
+    for k in (1..n).step_by(2) {
+        work[k] = work[k] - ((work[k - 1] + work[reflect(k as isize + 1, n)]) >> 1);
+    }
+    for k in (0..n).step_by(2) {
+        let sum = work[reflect(k as isize - 1, n)] + work[reflect(k as isize + 1, n)];
+        work[k] = work[k] + ((sum + two) >> 2);
+    }
+    let m = (n + 1) >> 1;
+    for k in 0..n {
+        data[if k % 2 == 0 { k >> 1 } else { m + (k >> 1) }] = work[k];
+    }
+}
+
+// Perform one level of the inverse lifting on the first `n` points by undoing
+// the steps of the forward lifting in the reverse order.
+fn lift_inverse<T>(data: &mut [T], n: usize, work: &mut [T]) where T: PrimInt + Signed {
+    let two = T::one() + T::one();
+    let m = (n + 1) >> 1;
+    for k in 0..n {
+        work[k] = data[if k % 2 == 0 { k >> 1 } else { m + (k >> 1) }];
+    }
+    for k in (0..n).step_by(2) {
+        let sum = work[reflect(k as isize - 1, n)] + work[reflect(k as isize + 1, n)];
+        work[k] = work[k] - ((sum + two) >> 2);
+    }
+    for k in (1..n).step_by(2) {
+        work[k] = work[k] + ((work[k - 1] + work[reflect(k as isize + 1, n)]) >> 1);
+    }
+    data[..n].copy_from_slice(&work[..n]);
+}
+
+// The low-pass analysis and synthesis filters are given in the standard
+// normalization by their halves starting from the central coefficient. The
+// high-pass filters are obtained by alternating the signs of the low-pass ones.
+// The low-pass and high-pass filters are centered at the even and odd samples,
+// respectively, as in the standard.
+const CDF97_ANALYSIS: [f64; 5] = [
+    0.6029490182363604, 0.26686411844287494, -0.07822326652899027, -0.016864118442874953,
+    0.02674875741081009,
+];
+const CDF97_SYNTHESIS: [f64; 4] = [
+    1.1150870524570005, 0.5912717631142501, -0.05754352622850018, -0.09127176311425009,
+];
+
+const LEGALL53_ANALYSIS: [f64; 3] = [0.75, 0.25, -0.125];
+const LEGALL53_SYNTHESIS: [f64; 2] = [1.0, 0.5];
+
+fn jpeg2000<T>(analysis: &[f64], synthesis: &[f64], normalization: Normalization) -> Wavelet<T>
+    where T: Float
+{
+    let scale = match normalization {
+        Normalization::Standard => 1.0,
+        Normalization::Balanced => SQRT_2,
+    };
+    Wavelet {
+        dec_lo: symmetric(analysis, scale, false, analysis.len() - 1),
+        dec_hi: symmetric(synthesis, 1.0 / scale, true, synthesis.len() - 2),
+        rec_lo: symmetric(synthesis, 1.0 / scale, false, synthesis.len() - 1),
+        rec_hi: symmetric(analysis, scale, true, analysis.len() - 2),
+        properties: None,
+    }
+}
+
+fn symmetric<T>(half: &[f64], scale: f64, alternate: bool, offset: usize) -> Filter<T>
+    where T: Float
+{
+    let m = half.len() - 1;
+    let coefficients = (0..(2 * m + 1)).map(|i| {
+        let j = m.abs_diff(i);
+        let value = scale * half[j];
+        T::from(if alternate && j % 2 == 1 { -value } else { value }).unwrap()
+    }).collect();
+    Filter { coefficients, offset }
+}
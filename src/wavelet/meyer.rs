@@ -0,0 +1,43 @@
+use num::Float;
+
+use wavelet::{Family, Wavelet, orthogonal};
+
+/// A discrete Meyer wavelet.
+///
+/// The wavelet is a finite impulse response approximation of the Meyer
+/// wavelet, and, hence, it is only approximately orthogonal.
+pub struct DiscreteMeyer;
+
+impl DiscreteMeyer {
+    /// Create a wavelet.
+    pub fn new<T>() -> Wavelet<T> where T: Float {
+        orthogonal(&COEFFICIENTS, COEFFICIENTS.len() / 2 - 1)
+            .describe(Family::DiscreteMeyer, "dmey".to_string(), 0, 0)
+    }
+}
+
+// The coefficients of the scaling filter are the ones of `dmey` in MATLAB and
+// PyWavelets.
+const COEFFICIENTS: [f64; 62] = [
+    -1.009999956941423e-12, 8.519459636796214e-09, -1.111944952595278e-08,
+    -1.0798819539621958e-08, 6.066975741351135e-08, -1.0866516536735883e-07,
+    8.200680650386481e-08, 1.1783004497663934e-07, -5.506340565252278e-07,
+    1.1307947017916706e-06, -1.489549216497156e-06, 7.367572885903746e-07,
+    3.20544191334478e-06, -1.6312699734552807e-05, 6.554305930575149e-05,
+    -0.0006011502343516092, -0.002704672124643725, 0.002202534100911002,
+    0.006045814097323304, -0.006387718318497156, -0.011061496392513451,
+    0.015270015130934803, 0.017423434103729693, -0.03213079399021176,
+    -0.024348745906078023, 0.0637390243228016, 0.030655091960824263,
+    -0.13284520043622938, -0.035087555656258346, 0.44459300275757724,
+    0.7445855923188063, 0.44459300275757724, -0.035087555656258346,
+    -0.13284520043622938, 0.030655091960824263, 0.0637390243228016,
+    -0.024348745906078023, -0.03213079399021176, 0.017423434103729693,
+    0.015270015130934803, -0.011061496392513451, -0.006387718318497156,
+    0.006045814097323304, 0.002202534100911002, -0.002704672124643725,
+    -0.0006011502343516092, 6.554305930575149e-05, -1.6312699734552807e-05,
+    3.20544191334478e-06, 7.367572885903746e-07, -1.489549216497156e-06,
+    1.1307947017916706e-06, -5.506340565252278e-07, 1.1783004497663934e-07,
+    8.200680650386481e-08, -1.0866516536735883e-07, 6.066975741351135e-08,
+    -1.0798819539621958e-08, -1.111944952595278e-08, 8.519459636796214e-09,
+    -1.009999956941423e-12, 0.0,
+];
@@ -0,0 +1,187 @@
+//! Wavelets.
+
+use num::{Float, FloatConst};
+
+use {Error, Result};
+
+mod biorthogonal;
+mod coiflets;
+mod daubechies;
+mod family;
+mod jpeg2000;
+mod meyer;
+mod properties;
+mod symlets;
+mod validation;
+
+pub use self::biorthogonal::{Biorthogonal, ReverseBiorthogonal};
+pub use self::coiflets::Coiflets;
+pub use self::daubechies::Daubechies;
+pub use self::family::Family;
+pub use self::jpeg2000::{Cdf97, LeGall53, Normalization};
+pub use self::meyer::DiscreteMeyer;
+pub use self::properties::{Symmetry, WaveletProperties};
+pub use self::symlets::Symlets;
+pub use self::validation::Validation;
+
+/// A wavelet.
+#[derive(Clone, Debug)]
+pub struct Wavelet<T> {
+    /// The decomposition low-pass filter.
+    pub dec_lo: Filter<T>,
+    /// The decomposition high-pass filter.
+    pub dec_hi: Filter<T>,
+
+    /// The reconstruction low-pass filter.
+    pub rec_lo: Filter<T>,
+    /// The reconstruction high-pass filter.
+    pub rec_hi: Filter<T>,
+
+    /// The properties, which are set by the constructors of the families.
+    pub properties: Option<WaveletProperties>,
+}
+
+/// A filter.
+///
+/// In the forward transform, coefficient `i` of a band is computed from the
+/// samples starting at `2 * i - offset`. In the inverse transform, it
+/// contributes to the same samples.
+#[derive(Clone, Debug)]
+pub struct Filter<T> {
+    /// The coefficients.
+    pub coefficients: Vec<T>,
+    /// The offset of the coefficients.
+    pub offset: usize,
+}
+
+/// A Haar wavelet.
+pub struct Haar;
+
+impl Haar {
+    /// Create a wavelet.
+    pub fn new<T>() -> Wavelet<T> where T: Float + FloatConst {
+        let value = T::FRAC_1_SQRT_2();
+        Wavelet {
+            dec_lo: Filter { coefficients: vec![value,  value], offset: 0 },
+            dec_hi: Filter { coefficients: vec![value, -value], offset: 0 },
+            rec_lo: Filter { coefficients: vec![value,  value], offset: 0 },
+            rec_hi: Filter { coefficients: vec![value, -value], offset: 0 },
+            properties: None,
+        }.describe(Family::Haar, "haar".to_string(), 1, 0)
+    }
+}
+
+impl<T> Wavelet<T> where T: Float {
+    /// Create an orthogonal wavelet given its scaling filter.
+    ///
+    /// The filter is given in the order in which it is applied to the signal,
+    /// that is, as the decomposition low-pass filter. The other filters are
+    /// derived by the quadrature-mirror relations, and all of them are aligned
+    /// in the same way as the ones of the Daubechies wavelets. Leading and
+    /// trailing zeros, which are present in some published filters, are
+    /// ignored.
+    ///
+    /// The function panics if the filter has fewer than two nonzero
+    /// coefficients, in which case the high-pass filters cannot be aligned with
+    /// the low-pass ones.
+    pub fn orthogonal(scaling: Vec<T>) -> Self {
+        let start = scaling.iter().position(|value| !value.is_zero());
+        let end = scaling.iter().rposition(|value| !value.is_zero());
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) if start < end => (start, end + 1),
+            _ => panic!("the scaling filter has fewer than two nonzero coefficients"),
+        };
+        let mut scaling = scaling[start..end].to_vec();
+        if scaling.len() % 2 == 1 {
+            scaling.push(T::zero());
+        }
+        orthogonal(&scaling, scaling.len() / 2 - 1)
+    }
+}
+
+impl<T> Wavelet<T> where T: Float + FloatConst {
+    /// Create a wavelet given its name.
+    ///
+    /// The names follow the convention of PyWavelets, such as `haar`, `db4`,
+    /// `sym8`, `coif3`, `bior3.5`, `rbio3.5`, and `dmey`. The JPEG 2000
+    /// wavelets are named `cdf97` and `legall53` and have the standard
+    /// normalization. The names of all the wavelets are given by
+    /// `Family::members`.
+    pub fn from_name(name: &str) -> Result<Self> {
+        let family = match Family::all().iter().find(|family| {
+            family.members().iter().any(|member| member == name)
+        }) {
+            Some(&family) => family,
+            _ => return Err(Error::UnknownWavelet(name.to_string())),
+        };
+        let mut orders = name[family.short_name().len()..].split('.').filter_map(|order| {
+            order.parse().ok()
+        });
+        let mut order = || orders.next().unwrap();
+        Ok(match family {
+            Family::Haar => Haar::new(),
+            Family::Daubechies => Daubechies::new(order()),
+            Family::Symlets => Symlets::new(order()),
+            Family::Coiflets => Coiflets::new(order()),
+            Family::Biorthogonal => Biorthogonal::new(order(), order()),
+            Family::ReverseBiorthogonal => ReverseBiorthogonal::new(order(), order()),
+            Family::DiscreteMeyer => DiscreteMeyer::new(),
+            Family::Cdf97 => Cdf97::new(Normalization::Standard),
+            Family::LeGall53 => LeGall53::new(Normalization::Standard),
+        })
+    }
+}
+
+impl<T> Filter<T> {
+    /// Return the number of coefficients.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Check if there are no coefficients.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+// The low-pass filters are given in the order in which they are applied to the
+// signal and padded with zeros to the same even length. With the default
+// offset, the filters are aligned in the same way as the periodization mode of
+// PyWavelets does. The zeros are trimmed afterwards.
+fn biorthogonal<T, U>(dec_lo: &[U], rec_lo: &[U], offset: usize) -> Wavelet<T>
+    where T: Float, U: Float
+{
+    let length = dec_lo.len();
+    debug_assert_eq!(rec_lo.len(), length);
+    let dec_hi = (0..length).map(|i| alternate(i, rec_lo[length - i - 1])).collect::<Vec<_>>();
+    let rec_hi = (0..length).map(|i| alternate(i, dec_lo[length - i - 1])).collect::<Vec<_>>();
+    Wavelet {
+        dec_lo: filter(dec_lo, offset),
+        dec_hi: filter(&dec_hi, offset),
+        rec_lo: filter(rec_lo, offset),
+        rec_hi: filter(&rec_hi, offset),
+        properties: None,
+    }
+}
+
+#[inline(always)]
+fn orthogonal<T, U>(scaling: &[U], offset: usize) -> Wavelet<T> where T: Float, U: Float {
+    biorthogonal(scaling, scaling, offset)
+}
+
+#[inline(always)]
+fn alternate<T>(i: usize, value: T) -> T where T: Float {
+    if i % 2 == 1 { -value } else { value }
+}
+
+fn filter<T, U>(coefficients: &[U], offset: usize) -> Filter<T> where T: Float, U: Float {
+    let start = coefficients.iter().position(|value| !value.is_zero()).unwrap();
+    let end = coefficients.iter().rposition(|value| !value.is_zero()).unwrap() + 1;
+    let offset = offset.checked_sub(start).expect("the filter starts after its offset");
+    Filter {
+        coefficients: coefficients[start..end].iter().map(|&value| T::from(value).unwrap()).collect(),
+        offset,
+    }
+}
@@ -0,0 +1,70 @@
+use wavelet::{Family, Wavelet};
+
+/// The properties of a wavelet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaveletProperties {
+    /// The family.
+    pub family: Family,
+    /// The name, which is the one accepted by `Wavelet::from_name`.
+    pub name: String,
+    /// The number of vanishing moments of the decomposition wavelet function.
+    pub vanishing_moments_psi: usize,
+    /// The number of vanishing moments of the decomposition scaling function.
+    pub vanishing_moments_phi: usize,
+    /// The symmetry.
+    pub symmetry: Symmetry,
+    /// Whether the wavelet is orthogonal.
+    pub orthogonal: bool,
+    /// Whether the wavelet is biorthogonal, which is the case for orthogonal
+    /// wavelets too.
+    pub biorthogonal: bool,
+    /// The width of the support of the scaling and wavelet functions of the
+    /// longest filter.
+    pub support_width: usize,
+    /// The number of coefficients of the longest filter with the leading and
+    /// trailing zeros trimmed.
+    ///
+    /// The number can be smaller than the filter length reported by
+    /// PyWavelets, which counts the zeros padding the filters to the same even
+    /// length; for instance, it is 61 instead of 62 for `dmey` and 5 instead of
+    /// 6 for `bior2.2`.
+    pub filter_length: usize,
+}
+
+/// A symmetry of a wavelet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Symmetry {
+    /// The filters are far from symmetric.
+    Asymmetric,
+    /// The filters are close to symmetric or antisymmetric.
+    NearSymmetric,
+    /// The filters are symmetric or antisymmetric.
+    Symmetric,
+}
+
+impl<T> Wavelet<T> {
+    pub(super) fn describe(mut self, family: Family, name: String, psi: usize, phi: usize) -> Self {
+        let filters = [&self.dec_lo, &self.dec_hi, &self.rec_lo, &self.rec_hi];
+        let filter_length = filters.iter().fold(0, |length, filter| length.max(filter.len()));
+        let symmetry = match family {
+            Family::Haar | Family::Daubechies => Symmetry::Asymmetric,
+            Family::Symlets | Family::Coiflets => Symmetry::NearSymmetric,
+            Family::Biorthogonal | Family::ReverseBiorthogonal | Family::DiscreteMeyer |
+            Family::Cdf97 | Family::LeGall53 => Symmetry::Symmetric,
+        };
+        let orthogonal = matches!(family, Family::Haar | Family::Daubechies | Family::Symlets |
+                                          Family::Coiflets | Family::DiscreteMeyer);
+        self.properties = Some(WaveletProperties {
+            family,
+            name,
+            vanishing_moments_psi: psi,
+            vanishing_moments_phi: phi,
+            symmetry,
+            orthogonal,
+            biorthogonal: true,
+            support_width: filter_length - 1,
+            filter_length,
+        });
+        self
+    }
+}
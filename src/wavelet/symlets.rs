@@ -0,0 +1,209 @@
+use num::Float;
+
+use wavelet::{Family, Wavelet, orthogonal};
+
+/// A symlet.
+pub struct Symlets;
+
+impl Symlets {
+    /// Create a wavelet.
+    ///
+    /// The number of vanishing moments should be between 2 and 20. The
+    /// wavelet with `moments` vanishing moments has `2 * moments` coefficients.
+    pub fn new<T>(moments: usize) -> Wavelet<T> where T: Float {
+        assert!(moments > 1 && moments <= COEFFICIENTS.len() + 1);
+        let coefficients = COEFFICIENTS[moments - 2];
+        orthogonal(coefficients, coefficients.len() / 2 - 1)
+            .describe(Family::Symlets, format!("sym{}", moments), moments, 0)
+    }
+}
+
+// The coefficients of the scaling filters are computed by spectral
+// factorization choosing the roots that make the phase response the closest to
+// linear (least asymmetric).
+pub(super) const COEFFICIENTS: &[&[f64]] = &[
+    &[
+        0.48296291314453416, 0.8365163037378079, 0.2241438680420134,
+        -0.12940952255126037,
+    ],
+    &[
+        0.33267055295008263, 0.8068915093110925, 0.45987750211849154,
+        -0.13501102001025458, -0.08544127388202666, 0.03522629188570953,
+    ],
+    &[
+        0.032223100604051466, -0.012603967262031304, -0.09921954357663353,
+        0.29785779560530606, 0.8037387518051321, 0.497618667632775,
+        -0.029635527646002493, -0.07576571478950221,
+    ],
+    &[
+        0.027333068344998768, 0.02951949092570626, -0.039134249302313844,
+        0.19939753397685558, 0.7234076904040407, 0.633978963456792,
+        0.01660210576451085, -0.17532808990805623, -0.021101834024689042,
+        0.019538882735249827,
+    ],
+    &[
+        0.015404109327044824, 0.0034907120842221626, -0.11799011114852002,
+        -0.04831174258569806, 0.49105594192797375, 0.787641141028651,
+        0.3379294217281658, -0.07263752278637658, -0.02106029251237085,
+        0.04472490177078139, 0.0017677118642540077, -0.00780070832503238,
+    ],
+    &[
+        0.002681814568260147, -0.001047384888679738, -0.012636303403240567,
+        0.030515513165877885, 0.06789269350122057, -0.04955283493704283,
+        0.017441255086835708, 0.5361019170905692, 0.7677643170048829,
+        0.2886296317506479, -0.14004724044293365, -0.10780823770328972,
+        0.0040102448715223955, 0.010268176708464817,
+    ],
+    &[
+        0.001889950332767689, -0.0003029205147241331, -0.014952258337062199,
+        0.0038087520138944896, 0.04913717967373029, -0.027219029917103486,
+        -0.0519458381078818, 0.36444189483617895, 0.777185751699628,
+        0.4813596512590534, -0.061273359067811076, -0.14329423835127267,
+        0.007607487324976609, 0.03169508781152599, -0.0005421323318000107,
+        -0.0033824159510050028,
+    ],
+    &[
+        0.001069490032908612, -0.00047315449868004354, -0.010264064027633121,
+        0.008859267493400267, 0.062077789302885746, -0.018233770779395506,
+        -0.19155083129728434, 0.03527248803527104, 0.6173384491409342,
+        0.7178970827644124, 0.23876091460730517, -0.05456895843083335,
+        0.0005834627461249819, 0.030224878858275187, -0.011528210207679187,
+        -0.013271967781817134, 0.0006197808889855071, 0.0014009155259146562,
+    ],
+    &[
+        0.0007701598091144599, 9.563267072285273e-05, -0.00864129927702215,
+        -0.0014653825813046104, 0.04592723923109151, 0.011609893903711319,
+        -0.1594942788849106, -0.07088053578323157, 0.4716906669384429,
+        0.7695100370210979, 0.3838267610670763, -0.035536740473819585,
+        -0.03199005688242811, 0.049994972077375154, 0.00576491203358115,
+        -0.02035493981231111, -0.0008043589320164513, 0.004593173585311792,
+        5.703608361849501e-05, -0.00045932942100465206,
+    ],
+    &[
+        0.000489263610261903, 0.0001105350976426903, -0.006389603666454665,
+        -0.0020034719001089793, 0.04300019068155133, 0.03526675956446462,
+        -0.1446023437053119, -0.2046547944957883, 0.23768990904925752,
+        0.7303435490883896, 0.5720229780100758, 0.09719839445890552,
+        -0.02283265102256226, 0.06997679961073293, 0.037037415978858186,
+        -0.02408084159586358, -0.009857934828789213, 0.00651249567477152,
+        0.0005883527353969825, -0.0017343662672978377, -3.8795655736148036e-05,
+        0.0001717219506993481,
+    ],
+    &[
+        0.00011196719424656528, -1.1353928041526612e-05, -0.001349755755571579,
+        0.00018021409008521752, 0.007414965517654315, -0.001408909244329129,
+        -0.024220722675013403, 0.007553780611679315, 0.0491793182996612,
+        -0.035848830736954634, -0.022162306170351302, 0.398885972390192,
+        0.7634790977836405, 0.46274103121928645, -0.07833262231631544,
+        -0.17037069723884962, 0.015301740622480154, 0.05780417944550475,
+        -0.002604391031331419, -0.014589836449233534, 0.00030764779631052455,
+        0.0023502976141833473, -1.8158078862632958e-05, -0.00017906658697508447,
+    ],
+    &[
+        7.042986690696273e-05, 3.690537342323894e-05, -0.0007213643851363755,
+        0.0004132611988416782, 0.005674853760123338, -0.0014924472742587286,
+        -0.020749686325520652, 0.017618296880645045, 0.09292603089914397,
+        0.008819757670429852, -0.14049009311367552, 0.11023022302128688,
+        0.6445643839011571, 0.6957391505615691, 0.19770481877126597,
+        -0.12436246075150338, -0.059750627717956466, 0.01386249743583841,
+        -0.017211642726304387, -0.020216768133395468, 0.005296359738721862,
+        0.00752622538996817, -0.00017094285852957213, -0.001136063438927969,
+        -3.573862364871594e-05, 6.820325263074355e-05,
+    ],
+    &[
+        4.461897799148456e-05, 1.9329016965548985e-05, -0.0006057601824664403,
+        -7.321421356689134e-05, 0.004532677471946337, 0.0010131419871843175,
+        -0.019439314263628174, -0.002365048836736659, 0.0698276163618212,
+        0.025898587531053823, -0.1599974111465199, -0.05811182331765858,
+        0.47533576263434446, 0.7599762419611892, 0.39320152196203945,
+        -0.03531811211510752, -0.05763449835141097, 0.03743308836282358,
+        0.0042805204990007525, -0.029196217764050975, -0.002753774791224789,
+        0.010037693717674817, 0.0003664765736599812, -0.0025794417259337628,
+        -6.286542481474576e-05, 0.00039843567297607205, 1.1210865808903235e-05,
+        -2.5879090265402585e-05,
+    ],
+    &[
+        2.866070852533231e-05, 2.1717890150808833e-05, -0.0004021685376030732,
+        -0.00010815440168565741, 0.0034810287370659995, 0.001526138278183266,
+        -0.01717125278164452, -0.008744788886485916, 0.06796982904489572,
+        0.06839331006051017, -0.13405629845628275, -0.19662635876631657,
+        0.24396270543218165, 0.7218430296363336, 0.5786404152151502,
+        0.11153369514258364, -0.041082666635469264, 0.040735479696770494,
+        0.021937642719737218, -0.03887671687685497, -0.019405011430946084,
+        0.010079977087906634, 0.0034234507363524206, -0.0035901654473736223,
+        -0.00026731644647202594, 0.0010705672194627174, 5.5122547855653366e-05,
+        -0.00016066186637499557, -7.359666798928679e-06, 9.712419737964491e-06,
+    ],
+    &[
+        6.230006701237647e-06, -3.1135564076138703e-06, -0.00010943147929558312,
+        2.8078582128206924e-05, 0.0008523547108065521, -0.00010844562230766216,
+        -0.0038809122526122205, 0.0007182119788254316, 0.012666731659876957,
+        -0.0031265171722736304, -0.03105120284364275, 0.004869274404814542,
+        0.03233309161058235, -0.0669830490706191, -0.03457422841769919,
+        0.39712293362039824, 0.7565249878763846, 0.47534280601234713,
+        -0.05404060138744081, -0.1595921921853958, 0.03072113906329964,
+        0.07803785290354831, -0.0035102750683370914, -0.024952758046315127,
+        0.0013598447424801486, 0.006937761130811371, -0.0002221164762103135,
+        -0.001338720606693644, 3.656592483330303e-05, 0.00016545679579123957,
+        -5.396483179313488e-06, -1.0797982104330864e-05,
+    ],
+    &[
+        3.7912531943316247e-06, -2.4527163425740825e-06, -7.607124405602918e-05,
+        2.5207933140671322e-05, 0.0007198270642145453, 5.840042869518092e-05,
+        -0.003932325279794941, -0.0019054076898564055, 0.012396988366634302,
+        0.009952982523507613, -0.01803889724190139, -0.007261634750933915,
+        0.01615880872591857, -0.08607087472063264, -0.1550760053497069,
+        0.18053958458074407, 0.681488995344317, 0.6507166292043823,
+        0.1423983504151139, -0.11856693261099856, 0.01727117821060019,
+        0.10475461484219489, 0.01790395221438949, -0.03329138349230622,
+        -0.004819212803181354, 0.010482366933016147, 0.0008567700701928022,
+        -0.0027416759756781813, -0.00013864230268101327, 0.00047599638026318304,
+        -1.3506383399799107e-05, -6.293702597545909e-05, 2.780126693825943e-06,
+        4.297343327338256e-06,
+    ],
+    &[
+        2.6126125564557025e-06, 1.3549157617851244e-06, -4.524675787451531e-05,
+        -1.4020992577002794e-05, 0.00039616840637938817, 7.021273458599636e-05,
+        -0.0023138718144868685, -0.0004115211092058262, 0.009502164390909605,
+        0.0016429863972087337, -0.03032509108914365, -0.00507708516041699,
+        0.08421992997007587, 0.03399566710354207, -0.15993814866769704,
+        -0.05202915898042007, 0.47396905989574695, 0.7536291400999388,
+        0.40148386056768737, -0.03248057329150485, -0.07379920729088593,
+        0.028529597038742298, 0.00627794455413226, -0.03171268473169947,
+        -0.0032607441999778558, 0.01501235634421641, 0.0010877847895682568,
+        -0.005239789683013974, -0.0001887762394005706, 0.0014280863270799422,
+        4.741614518228368e-05, -0.000265830110241981, -9.858816030038168e-06,
+        2.955743762087669e-05, 7.847298055848573e-07, -1.5131530692320486e-06,
+    ],
+    &[
+        1.7509367995304997e-06, 2.062317063229324e-06, -2.8151138661488743e-05,
+        -1.6821387029242595e-05, 0.00027621877685681965, 0.00012930767650608303,
+        -0.0017049602611613154, -0.0006179223277899935, 0.008262236955522643,
+        0.004319351874887417, -0.027709896931223672, -0.016908234861133548,
+        0.08407267627938503, 0.09363084341592179, -0.11624173010700133,
+        -0.17659686625099993, 0.2582661692381038, 0.7195555257159846,
+        0.5781449453372968, 0.10902582508022089, -0.067525058040684,
+        0.008954591172977125, 0.007015573857219181, -0.04663598353477771,
+        -0.022651993378066386, 0.015797439295764448, 0.007968438320637783,
+        -0.005122205002569428, -0.0011607032571970346, 0.0021214250281832055,
+        0.00015915804767957373, -0.0006357645150042333, -4.612039600171763e-05,
+        0.00011553923333583907, 8.873312173693282e-06, -1.1880518269831197e-05,
+        -6.463651303333404e-07, 5.487732768218514e-07,
+    ],
+    &[
+        3.695537474791267e-07, -1.9015675892278172e-07, -7.91936141189395e-06,
+        3.0256660631185363e-06, 7.992967835712114e-05, -1.9284123010161865e-05,
+        -0.0004947310915655073, 7.215991190073666e-05, 0.0020889947081866745,
+        -0.00030526283188065685, -0.006606585799120731, 0.0014230873596194143,
+        0.0170040490232798, -0.003313857384407233, -0.03162943714548432,
+        0.008123228356394549, 0.025579349509566317, -0.0789943449267614,
+        -0.02981936887124318, 0.4058314443632748, 0.7511627284288979,
+        0.4719914750911054, -0.05108834293600639, -0.16057829842072482,
+        0.03625095165576088, 0.08891966802862601, -0.006843701966974055,
+        -0.03537333675746389, 0.0019385970676619735, 0.012157040948987497,
+        -0.0006111263859779794, -0.003471647802925689, 0.00012544091727041256,
+        0.0007476108598012617, -2.661555034277681e-05, -0.00011739133516628476,
+        4.525422210086227e-06, 1.2287252778374232e-05, -3.2567026426308275e-07,
+        -6.329129045042896e-07,
+    ],
+];
@@ -0,0 +1,170 @@
+use num::Float;
+
+use wavelet::{Filter, Wavelet};
+
+/// A report on the validity of a wavelet.
+///
+/// The numerical conditions are checked with a tolerance equal to the square
+/// root of the machine epsilon of the floating-point type of the wavelet. A
+/// moment is considered vanishing if it is below a small multiple of the
+/// machine epsilon relative to the sum of the magnitudes of its terms; in single
+/// precision, large numbers of vanishing moments are overestimated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Validation {
+    /// Whether the filters are nonempty and have offsets smaller than their
+    /// lengths, which is what the transform requires.
+    pub well_formed: bool,
+    /// Whether the wavelet is orthogonal, that is, biorthogonal with the
+    /// reconstruction filters equal to the decomposition ones.
+    pub orthogonal: bool,
+    /// Whether the decomposition filters are biorthogonal to the
+    /// reconstruction ones with respect to even shifts.
+    pub biorthogonal: bool,
+    /// Whether the inverse transform undoes the forward one.
+    pub perfect_reconstruction: bool,
+    /// The number of vanishing moments of the decomposition high-pass filter.
+    pub dec_vanishing_moments: usize,
+    /// The number of vanishing moments of the reconstruction high-pass filter.
+    pub rec_vanishing_moments: usize,
+}
+
+impl Validation {
+    /// Check if the wavelet can be used for perfect reconstruction.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.well_formed && self.perfect_reconstruction
+    }
+}
+
+impl<T> Wavelet<T> where T: Float {
+    /// Validate the filters.
+    pub fn validate(&self) -> Validation {
+        if !self.is_well_formed() {
+            return Validation {
+                well_formed: false,
+                orthogonal: false,
+                biorthogonal: false,
+                perfect_reconstruction: false,
+                dec_vanishing_moments: 0,
+                rec_vanishing_moments: 0,
+            };
+        }
+        let epsilon = T::epsilon().to_f64().unwrap();
+        let tolerance = epsilon.sqrt();
+        let dec_lo = Sequence::new(&self.dec_lo);
+        let dec_hi = Sequence::new(&self.dec_hi);
+        let rec_lo = Sequence::new(&self.rec_lo);
+        let rec_hi = Sequence::new(&self.rec_hi);
+        let biorthogonal = {
+            biorthogonal(&dec_lo, &rec_lo, 1.0, tolerance) &&
+            biorthogonal(&dec_hi, &rec_hi, 1.0, tolerance) &&
+            biorthogonal(&dec_lo, &rec_hi, 0.0, tolerance) &&
+            biorthogonal(&dec_hi, &rec_lo, 0.0, tolerance)
+        };
+        Validation {
+            well_formed: true,
+            orthogonal: {
+                biorthogonal &&
+                dec_lo.equals(&rec_lo, tolerance) &&
+                dec_hi.equals(&rec_hi, tolerance)
+            },
+            biorthogonal,
+            perfect_reconstruction: reconstructs(&dec_lo, &dec_hi, &rec_lo, &rec_hi, tolerance),
+            dec_vanishing_moments: dec_hi.vanishing_moments(16.0 * epsilon),
+            rec_vanishing_moments: rec_hi.vanishing_moments(16.0 * epsilon),
+        }
+    }
+
+    /// Check if the filters are nonempty and have offsets smaller than their
+    /// lengths.
+    pub fn is_well_formed(&self) -> bool {
+        [&self.dec_lo, &self.dec_hi, &self.rec_lo, &self.rec_hi].iter().all(|filter| {
+            filter.offset < filter.len()
+        })
+    }
+}
+
+// A filter as a sequence on the integers, where position zero corresponds to
+// the sample at which the filter is applied.
+struct Sequence {
+    coefficients: Vec<f64>,
+    start: isize,
+}
+
+impl Sequence {
+    fn new<T>(filter: &Filter<T>) -> Sequence where T: Float {
+        Sequence {
+            coefficients: filter.coefficients.iter().map(|value| value.to_f64().unwrap()).collect(),
+            start: -(filter.offset as isize),
+        }
+    }
+
+    #[inline]
+    fn end(&self) -> isize {
+        self.start + self.coefficients.len() as isize
+    }
+
+    #[inline]
+    fn get(&self, i: isize) -> f64 {
+        if i < self.start || i >= self.end() {
+            0.0
+        } else {
+            self.coefficients[(i - self.start) as usize]
+        }
+    }
+
+    fn equals(&self, other: &Sequence, tolerance: f64) -> bool {
+        let start = self.start.min(other.start);
+        let end = self.end().max(other.end());
+        (start..end).all(|i| (self.get(i) - other.get(i)).abs() <= tolerance)
+    }
+
+    fn vanishing_moments(&self, tolerance: f64) -> usize {
+        let center = (self.start + self.end() - 1) as f64 / 2.0;
+        let positions = (self.start..self.end()).map(|i| i as f64 - center).collect::<Vec<_>>();
+        (0..self.coefficients.len()).take_while(|&k| {
+            let (mut sum, mut scale) = (0.0, 0.0);
+            for (&position, &coefficient) in positions.iter().zip(&self.coefficients) {
+                let term = position.powi(k as i32) * coefficient;
+                sum += term;
+                scale += term.abs();
+            }
+            sum.abs() <= tolerance * scale
+        }).count()
+    }
+}
+
+// Check that the correlation of the two filters at even shifts is the given
+// value at shift zero and zero elsewhere.
+fn biorthogonal(first: &Sequence, second: &Sequence, value: f64, tolerance: f64) -> bool {
+    let start = first.start - second.end();
+    let end = first.end() - second.start;
+    (start..end).filter(|shift| shift % 2 == 0).all(|shift| {
+        let sum = (first.start..first.end()).fold(0.0, |sum, i| {
+            sum + first.get(i) * second.get(i - shift)
+        });
+        let expected = if shift == 0 { value } else { 0.0 };
+        (sum - expected).abs() <= tolerance
+    })
+}
+
+// Check that the output of one forward and one inverse step at sample `p`
+// depends only on the input at sample `p` with unit gain. Since the step is
+// invariant to shifts by two samples, it suffices to consider `p` equal to zero
+// and one.
+fn reconstructs(dec_lo: &Sequence, dec_hi: &Sequence, rec_lo: &Sequence, rec_hi: &Sequence,
+                tolerance: f64) -> bool {
+    let span = [dec_lo, dec_hi, rec_lo, rec_hi].iter().fold(0, |span, filter| {
+        span.max(filter.start.abs()).max(filter.end().abs())
+    });
+    (0..2).all(|p| {
+        (-2 * span..(2 * span + 2)).all(|q| {
+            let sum = (-span..(span + 1)).fold(0.0, |sum, i| {
+                sum + rec_lo.get(p - 2 * i) * dec_lo.get(q - 2 * i) +
+                      rec_hi.get(p - 2 * i) * dec_hi.get(q - 2 * i)
+            });
+            let expected = if p == q { 1.0 } else { 0.0 };
+            (sum - expected).abs() <= tolerance
+        })
+    })
+}
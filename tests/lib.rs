@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision)]
+
 extern crate assert;
 extern crate dwt;
 
@@ -300,3 +302,1382 @@ fn inverse_haar_64() {
         assert::close(&data, &expected_data_2[..], 1e-14);
     }
 }
+
+#[test]
+fn forward_daubechies_64() {
+    let data = vec![
+        4.217612826262750e-01, 9.157355251890671e-01, 7.922073295595544e-01, 9.594924263929030e-01,
+        6.557406991565868e-01, 3.571167857418955e-02, 8.491293058687771e-01, 9.339932477575505e-01,
+        6.787351548577735e-01, 7.577401305783334e-01, 7.431324681249162e-01, 3.922270195341682e-01,
+        6.554778901775566e-01, 1.711866878115618e-01, 7.060460880196088e-01, 3.183284637742068e-02,
+        2.769229849608900e-01, 4.617139063115394e-02, 9.713178123584754e-02, 8.234578283272926e-01,
+        6.948286229758170e-01, 3.170994800608605e-01, 9.502220488383549e-01, 3.444608050290876e-02,
+        4.387443596563982e-01, 3.815584570930084e-01, 7.655167881490024e-01, 7.951999011370632e-01,
+        1.868726045543786e-01, 4.897643957882311e-01, 4.455862007108995e-01, 6.463130101112646e-01,
+        7.093648308580726e-01, 7.546866819823609e-01, 2.760250769985784e-01, 6.797026768536748e-01,
+        6.550980039738407e-01, 1.626117351946306e-01, 1.189976815583766e-01, 4.983640519821430e-01,
+        9.597439585160811e-01, 3.403857266661332e-01, 5.852677509797773e-01, 2.238119394911370e-01,
+        7.512670593056529e-01, 2.550951154592691e-01, 5.059570516651424e-01, 6.990767226566860e-01,
+        8.909032525357985e-01, 9.592914252054443e-01, 5.472155299638031e-01, 1.386244428286791e-01,
+        1.492940055590575e-01, 2.575082541237365e-01, 8.407172559836625e-01, 2.542821789715310e-01,
+        8.142848260688164e-01, 2.435249687249893e-01, 9.292636231872278e-01, 3.499837659848087e-01,
+        1.965952504312082e-01, 2.510838579760311e-01, 6.160446761466392e-01, 4.732888489027293e-01,
+    ];
+
+    let expected_data_3 = [
+         1.446364503045514e+00,  1.222316004897263e+00,
+         2.026216746763465e+00,  1.443330051634128e+00,
+         1.136499875465727e+00,  1.531927763018350e+00,
+         1.362808722350369e+00,  1.560500188638691e+00,
+         1.624627480603300e-02, -5.123875246093722e-01,
+         6.143022483951899e-02,  4.355606414876260e-02,
+        -9.176810460577728e-03,  5.039040048774719e-01,
+         9.201309734932016e-02, -5.023376614164636e-02,
+        -6.670673474492042e-01,  1.462676759226664e-01,
+        -1.167387445496626e-01, -2.674940797534079e-01,
+         1.899572499740922e-01, -3.637819493094859e-01,
+        -3.174182685687615e-01,  2.757649891922802e-01,
+        -8.449511753618435e-03,  4.621695453793404e-01,
+        -3.802391527510614e-02,  4.992043944151999e-01,
+        -3.470066442861188e-01, -7.070511726098287e-02,
+        -4.126267768824786e-01, -1.469948003906784e-02,
+         7.997034528936969e-02, -4.531081079593298e-01,
+         9.108148369432664e-02,  4.431637485915600e-02,
+        -2.332054660714745e-01, -3.346448861063765e-01,
+        -3.890351608498007e-01, -5.334357884138290e-02,
+         3.937472349657581e-01, -4.434824644633103e-01,
+        -5.331333225588687e-01, -2.543182194106568e-02,
+         8.028359728283858e-02,  2.474600047623681e-01,
+         3.403622225133267e-02,  9.415738432642891e-02,
+         2.724718488416879e-01, -2.987547714529795e-01,
+         1.819411287255405e-01, -5.124120436667476e-01,
+        -2.026040062542744e-01, -3.313076778445425e-01,
+         9.271435025320082e-02,  5.348665757791408e-02,
+        -1.385214856341709e-01, -1.243941421613023e-02,
+        -5.393803594474463e-01, -4.020241343787394e-01,
+        -2.584079946793599e-01,  5.558580732777763e-02,
+        -1.400861225520321e-01,  3.055699302123002e-01,
+    ];
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &dwt::wavelet::Daubechies::new(4), 3);
+        assert::close(&data, &expected_data_3[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_daubechies() {
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    for moments in 1..39 {
+        let wavelet = dwt::wavelet::Daubechies::new(moments);
+        let mut other = data.clone();
+        transform(&mut other, Operation::Forward, &wavelet, 4);
+        transform(&mut other, Operation::Inverse, &wavelet, 4);
+        assert::close(&other, &data, 1e-12);
+    }
+}
+
+#[test]
+fn forward_symlets_64() {
+    let data = vec![
+        4.217612826262750e-01, 9.157355251890671e-01, 7.922073295595544e-01, 9.594924263929030e-01,
+        6.557406991565868e-01, 3.571167857418955e-02, 8.491293058687771e-01, 9.339932477575505e-01,
+        6.787351548577735e-01, 7.577401305783334e-01, 7.431324681249162e-01, 3.922270195341682e-01,
+        6.554778901775566e-01, 1.711866878115618e-01, 7.060460880196088e-01, 3.183284637742068e-02,
+        2.769229849608900e-01, 4.617139063115394e-02, 9.713178123584754e-02, 8.234578283272926e-01,
+        6.948286229758170e-01, 3.170994800608605e-01, 9.502220488383549e-01, 3.444608050290876e-02,
+        4.387443596563982e-01, 3.815584570930084e-01, 7.655167881490024e-01, 7.951999011370632e-01,
+        1.868726045543786e-01, 4.897643957882311e-01, 4.455862007108995e-01, 6.463130101112646e-01,
+        7.093648308580726e-01, 7.546866819823609e-01, 2.760250769985784e-01, 6.797026768536748e-01,
+        6.550980039738407e-01, 1.626117351946306e-01, 1.189976815583766e-01, 4.983640519821430e-01,
+        9.597439585160811e-01, 3.403857266661332e-01, 5.852677509797773e-01, 2.238119394911370e-01,
+        7.512670593056529e-01, 2.550951154592691e-01, 5.059570516651424e-01, 6.990767226566860e-01,
+        8.909032525357985e-01, 9.592914252054443e-01, 5.472155299638031e-01, 1.386244428286791e-01,
+        1.492940055590575e-01, 2.575082541237365e-01, 8.407172559836625e-01, 2.542821789715310e-01,
+        8.142848260688164e-01, 2.435249687249893e-01, 9.292636231872278e-01, 3.499837659848087e-01,
+        1.965952504312082e-01, 2.510838579760311e-01, 6.160446761466392e-01, 4.732888489027293e-01,
+    ];
+
+    let expected_data_3 = [
+         2.060386976552071e+00,  1.011050946087842e+00,
+         1.383477675809174e+00,  1.551717955700163e+00,
+         1.301381449150297e+00,  1.657890009062709e+00,
+         1.241940892142446e+00,  1.522117951308807e+00,
+        -7.327206742125349e-02, -1.965507928693470e-01,
+        -3.528887599747272e-01,  1.092982366770116e-01,
+         8.873007673162812e-02,  2.817301557723590e-01,
+         4.776200189295718e-01,  1.599771355558470e-01,
+        -1.721715880263782e-01,  6.249427634624165e-01,
+        -3.009692645055381e-03, -1.204349251832321e-01,
+         4.323062396914497e-01, -1.320440670861613e-01,
+        -1.223346365565113e-01,  4.079594952662624e-01,
+         3.353593559939193e-02,  4.869079603508843e-01,
+        -1.061681698126459e-01,  2.581626702165680e-01,
+        -5.132965557782272e-01, -9.691038191634831e-02,
+        -1.291056829868276e-01,  1.213410960211682e-01,
+         2.388940819334836e-01,  1.384417475364704e-01,
+        -1.081827507102503e-01, -3.900140637579486e-01,
+         2.742963316296096e-01, -1.715667045217556e-01,
+        -2.742366661705895e-01, -4.352046829832018e-01,
+        -1.959879689770041e-01,  2.442280270129349e-01,
+         9.143537035022419e-03, -6.683395438103040e-01,
+        -1.359241867431787e-01, -1.123177526536020e-01,
+         3.817161180007090e-01,  4.374054744810352e-02,
+        -2.104928395624013e-02,  3.956577936569422e-01,
+        -2.462274867661511e-01,  1.530606208309520e-01,
+        -3.897731348432888e-01, -1.978692044173564e-01,
+        -4.130883575192177e-01, -2.152346599481363e-03,
+         1.796035844813864e-02,  1.604752373814254e-02,
+        -3.351081220425211e-02, -4.300833460457147e-01,
+        -3.684625377040575e-01, -4.831621148514842e-01,
+         1.428729708692842e-01, -2.534071614527469e-01,
+    ];
+
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &dwt::wavelet::Symlets::new(8), 3);
+        assert::close(&data, &expected_data_3[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_symlets() {
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    for moments in 2..21 {
+        let wavelet = dwt::wavelet::Symlets::new(moments);
+        let mut other = data.clone();
+        transform(&mut other, Operation::Forward, &wavelet, 4);
+        transform(&mut other, Operation::Inverse, &wavelet, 4);
+        assert::close(&other, &data, 1e-12);
+    }
+}
+
+#[test]
+fn forward_coiflets_64() {
+    let data = vec![
+        4.217612826262750e-01, 9.157355251890671e-01, 7.922073295595544e-01, 9.594924263929030e-01,
+        6.557406991565868e-01, 3.571167857418955e-02, 8.491293058687771e-01, 9.339932477575505e-01,
+        6.787351548577735e-01, 7.577401305783334e-01, 7.431324681249162e-01, 3.922270195341682e-01,
+        6.554778901775566e-01, 1.711866878115618e-01, 7.060460880196088e-01, 3.183284637742068e-02,
+        2.769229849608900e-01, 4.617139063115394e-02, 9.713178123584754e-02, 8.234578283272926e-01,
+        6.948286229758170e-01, 3.170994800608605e-01, 9.502220488383549e-01, 3.444608050290876e-02,
+        4.387443596563982e-01, 3.815584570930084e-01, 7.655167881490024e-01, 7.951999011370632e-01,
+        1.868726045543786e-01, 4.897643957882311e-01, 4.455862007108995e-01, 6.463130101112646e-01,
+        7.093648308580726e-01, 7.546866819823609e-01, 2.760250769985784e-01, 6.797026768536748e-01,
+        6.550980039738407e-01, 1.626117351946306e-01, 1.189976815583766e-01, 4.983640519821430e-01,
+        9.597439585160811e-01, 3.403857266661332e-01, 5.852677509797773e-01, 2.238119394911370e-01,
+        7.512670593056529e-01, 2.550951154592691e-01, 5.059570516651424e-01, 6.990767226566860e-01,
+        8.909032525357985e-01, 9.592914252054443e-01, 5.472155299638031e-01, 1.386244428286791e-01,
+        1.492940055590575e-01, 2.575082541237365e-01, 8.407172559836625e-01, 2.542821789715310e-01,
+        8.142848260688164e-01, 2.435249687249893e-01, 9.292636231872278e-01, 3.499837659848087e-01,
+        1.965952504312082e-01, 2.510838579760311e-01, 6.160446761466392e-01, 4.732888489027293e-01,
+    ];
+
+    let expected_data_3 = [
+         1.637448140496512e+00,  1.970894166007187e+00,
+         9.287408744278792e-01,  1.437473486720212e+00,
+         1.571644486397998e+00,  1.300966111472785e+00,
+         1.642407835991532e+00,  1.240388754299403e+00,
+        -2.849413829171669e-02, -4.664398875530812e-01,
+         1.089969415902711e-01,  2.583752382817480e-01,
+         2.970010995231746e-01,  6.124030052925575e-01,
+         4.066076340440170e-01,  7.831584253019852e-02,
+         3.919557003153366e-02, -2.950087161580290e-02,
+        -1.818838967730080e-01,  1.410623394181183e-01,
+        -6.502933614869114e-02, -5.034648539407071e-01,
+         1.216321538603892e-01,  1.599271759119999e-01,
+         5.475021743929094e-01,  2.022411685855262e-01,
+         2.962779342505719e-01, -1.783254044436997e-02,
+        -3.226712838509616e-01, -3.135693652702359e-01,
+        -1.222954558190210e-01, -4.606345759934922e-01,
+        -2.068266460586693e-01,  6.020521518821172e-01,
+        -1.822630770961676e-01, -2.444076861907557e-02,
+         2.257985881918257e-01,  3.695166890970523e-01,
+         3.309755406403043e-01,  9.720381570342596e-02,
+        -3.889396745586244e-01,  4.340207314545640e-01,
+         4.824770742481536e-01,  1.502110886997206e-01,
+        -2.432685090672702e-01, -1.381778343195744e-01,
+        -2.510848138932380e-02, -1.817003344509543e-01,
+        -1.829940237768303e-01,  1.977202439082403e-01,
+        -3.002509158099106e-02,  3.612159894356273e-01,
+         2.946697838787798e-01,  2.762346857303605e-01,
+        -7.406911712957462e-04, -1.763515051888395e-01,
+         1.581271587327258e-01,  1.182321793149568e-01,
+         4.421839318292206e-01,  4.758339365387650e-01,
+         1.318794794338303e-01,  8.537236067745343e-02,
+         5.251477565293166e-02, -2.309031192244068e-01,
+    ];
+
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &dwt::wavelet::Coiflets::new(2), 3);
+        assert::close(&data, &expected_data_3[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_coiflets() {
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    for order in 1..18 {
+        let wavelet = dwt::wavelet::Coiflets::new(order);
+        let mut other = data.clone();
+        transform(&mut other, Operation::Forward, &wavelet, 4);
+        transform(&mut other, Operation::Inverse, &wavelet, 4);
+        assert::close(&other, &data, 1e-12);
+    }
+}
+
+#[test]
+fn forward_biorthogonal_64() {
+    let data = vec![
+        4.217612826262750e-01, 9.157355251890671e-01, 7.922073295595544e-01, 9.594924263929030e-01,
+        6.557406991565868e-01, 3.571167857418955e-02, 8.491293058687771e-01, 9.339932477575505e-01,
+        6.787351548577735e-01, 7.577401305783334e-01, 7.431324681249162e-01, 3.922270195341682e-01,
+        6.554778901775566e-01, 1.711866878115618e-01, 7.060460880196088e-01, 3.183284637742068e-02,
+        2.769229849608900e-01, 4.617139063115394e-02, 9.713178123584754e-02, 8.234578283272926e-01,
+        6.948286229758170e-01, 3.170994800608605e-01, 9.502220488383549e-01, 3.444608050290876e-02,
+        4.387443596563982e-01, 3.815584570930084e-01, 7.655167881490024e-01, 7.951999011370632e-01,
+        1.868726045543786e-01, 4.897643957882311e-01, 4.455862007108995e-01, 6.463130101112646e-01,
+        7.093648308580726e-01, 7.546866819823609e-01, 2.760250769985784e-01, 6.797026768536748e-01,
+        6.550980039738407e-01, 1.626117351946306e-01, 1.189976815583766e-01, 4.983640519821430e-01,
+        9.597439585160811e-01, 3.403857266661332e-01, 5.852677509797773e-01, 2.238119394911370e-01,
+        7.512670593056529e-01, 2.550951154592691e-01, 5.059570516651424e-01, 6.990767226566860e-01,
+        8.909032525357985e-01, 9.592914252054443e-01, 5.472155299638031e-01, 1.386244428286791e-01,
+        1.492940055590575e-01, 2.575082541237365e-01, 8.407172559836625e-01, 2.542821789715310e-01,
+        8.142848260688164e-01, 2.435249687249893e-01, 9.292636231872278e-01, 3.499837659848087e-01,
+        1.965952504312082e-01, 2.510838579760311e-01, 6.160446761466392e-01, 4.732888489027293e-01,
+    ];
+
+    let expected_data_3 = [
+         1.592192363774348e+00,  2.203422742441717e+00,
+         6.878097354634630e-01,  1.476348622490133e+00,
+         1.672786694648970e+00,  1.167569073693478e+00,
+         1.774197829603517e+00,  1.155636793697881e+00,
+         1.554294742034263e-01, -9.993514588579440e-02,
+        -5.905519261150435e-01,  8.117841692820260e-02,
+         3.694743812712055e-01,  5.440893401887633e-01,
+         9.059030411068018e-01,  6.010729417991668e-01,
+        -4.962441744625088e-01, -3.283145820862000e-02,
+        -7.003181719711775e-02, -1.718629303506646e-01,
+         1.784020124475428e-01, -1.468857477618177e-01,
+        -5.544139006969618e-01,  7.450143525860521e-02,
+         2.908745199197843e-01,  6.957487659656371e-01,
+         3.451825936431963e-01,  3.543688362476408e-01,
+        -5.644737235814320e-02, -3.838738664106396e-01,
+        -4.148841417715299e-01, -2.444286250403677e-01,
+        -2.183200807225028e-01, -1.665366662441174e-01,
+         5.067999225914706e-01, -1.202512986149755e-01,
+        -3.309706562879099e-02,  2.171370490195139e-01,
+         3.603241510652305e-01,  3.250228270626387e-01,
+         9.960022744432886e-02, -3.022723283067229e-01,
+         3.573900500558805e-01,  4.667167260345776e-01,
+         1.559680395368044e-01, -2.255707435375403e-01,
+        -1.227077704334803e-01, -4.867545903835564e-02,
+        -1.852561275217903e-01, -1.514206496564279e-01,
+         1.587002936069056e-01,  2.899616376629508e-02,
+         3.055550728538374e-01,  3.142774736976555e-01,
+         2.641163611805245e-01, -4.571944248155599e-04,
+        -1.698697002682725e-01,  1.482310243022877e-01,
+         1.679360055415502e-01,  4.053269444629205e-01,
+         4.442393091244371e-01,  1.505753278642582e-01,
+         1.097685027517354e-01,  3.225406098297191e-02,
+    ];
+
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &dwt::wavelet::Biorthogonal::new(2, 4), 3);
+        assert::close(&data, &expected_data_3[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_biorthogonal() {
+    use dwt::wavelet::{Biorthogonal, ReverseBiorthogonal};
+
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    let orders = [
+        (1, 1), (1, 3), (1, 5), (2, 2), (2, 4), (2, 6), (2, 8), (3, 1),
+        (3, 3), (3, 5), (3, 7), (3, 9), (4, 4), (5, 5), (6, 8),
+    ];
+    for &(reconstruction, decomposition) in orders.iter() {
+        let wavelets = [
+            Biorthogonal::new(reconstruction, decomposition),
+            ReverseBiorthogonal::new(reconstruction, decomposition),
+        ];
+        for wavelet in wavelets.iter() {
+            let mut other = data.clone();
+            transform(&mut other, Operation::Forward, wavelet, 4);
+            transform(&mut other, Operation::Inverse, wavelet, 4);
+            assert::close(&other, &data, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn forward_cdf97_40() {
+    use dwt::wavelet::{Cdf97, Normalization};
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_data_2 = [
+         3.079320064327630e-01,  1.563859166219480e-01,
+         6.231818637804500e-01,  4.862649982726260e-01,
+         4.833254474432636e-01,  3.111313292242283e-01,
+         5.534793927132488e-01,  2.944030432213944e-01,
+         4.524225052362399e-01,  5.184396764750314e-01,
+         4.399830812308914e-04, -2.483627136812520e-01,
+        -7.007410677489831e-02, -1.552016744314635e-01,
+        -1.026753147421081e-02,  5.887264300976723e-01,
+        -4.838869273255041e-01, -7.080406776761879e-01,
+         1.329219189604881e-01,  2.025455495853941e-01,
+        -2.279382479963438e-01, -5.650445879310739e-02,
+         6.446800621139479e-03, -7.316862508051882e-01,
+         2.674336732947218e-01,  6.557689130443294e-02,
+         6.670939368964401e-01, -5.770008421196795e-01,
+        -1.567896664262770e-01,  3.776375786677613e-01,
+        -4.705790307632194e-01,  1.882589491837378e-01,
+        -3.266708353808020e-01, -1.784641640478808e-01,
+        -4.431830143776231e-01, -1.297233269524173e-01,
+         6.731791117547357e-02, -1.355475141644882e-01,
+        -3.743494817036958e-01, -4.167909818003753e-01,
+    ];
+
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &Cdf97::new(Normalization::Standard), 2);
+        assert::close(&data, &expected_data_2[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_jpeg2000() {
+    use dwt::wavelet::{Cdf97, LeGall53, Normalization};
+
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    for &normalization in [Normalization::Standard, Normalization::Balanced].iter() {
+        for wavelet in [Cdf97::new(normalization), LeGall53::new(normalization)].iter() {
+            let mut other = data.clone();
+            transform(&mut other, Operation::Forward, wavelet, 4);
+            transform(&mut other, Operation::Inverse, wavelet, 4);
+            assert::close(&other, &data, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn forward_discrete_meyer_64() {
+    let data = vec![
+        4.217612826262750e-01, 9.157355251890671e-01, 7.922073295595544e-01, 9.594924263929030e-01,
+        6.557406991565868e-01, 3.571167857418955e-02, 8.491293058687771e-01, 9.339932477575505e-01,
+        6.787351548577735e-01, 7.577401305783334e-01, 7.431324681249162e-01, 3.922270195341682e-01,
+        6.554778901775566e-01, 1.711866878115618e-01, 7.060460880196088e-01, 3.183284637742068e-02,
+        2.769229849608900e-01, 4.617139063115394e-02, 9.713178123584754e-02, 8.234578283272926e-01,
+        6.948286229758170e-01, 3.170994800608605e-01, 9.502220488383549e-01, 3.444608050290876e-02,
+        4.387443596563982e-01, 3.815584570930084e-01, 7.655167881490024e-01, 7.951999011370632e-01,
+        1.868726045543786e-01, 4.897643957882311e-01, 4.455862007108995e-01, 6.463130101112646e-01,
+        7.093648308580726e-01, 7.546866819823609e-01, 2.760250769985784e-01, 6.797026768536748e-01,
+        6.550980039738407e-01, 1.626117351946306e-01, 1.189976815583766e-01, 4.983640519821430e-01,
+        9.597439585160811e-01, 3.403857266661332e-01, 5.852677509797773e-01, 2.238119394911370e-01,
+        7.512670593056529e-01, 2.550951154592691e-01, 5.059570516651424e-01, 6.990767226566860e-01,
+        8.909032525357985e-01, 9.592914252054443e-01, 5.472155299638031e-01, 1.386244428286791e-01,
+        1.492940055590575e-01, 2.575082541237365e-01, 8.407172559836625e-01, 2.542821789715310e-01,
+        8.142848260688164e-01, 2.435249687249893e-01, 9.292636231872278e-01, 3.499837659848087e-01,
+        1.965952504312082e-01, 2.510838579760311e-01, 6.160446761466392e-01, 4.732888489027293e-01,
+    ];
+
+    let expected_data_6 = [
+         4.146145985988717e+00,  2.016462582547559e-01,
+         2.485412571683756e-01,  1.864048466581095e-01,
+        -4.993678558784793e-01, -1.882718805822992e-01,
+         1.427587979174316e-01,  4.142826738677882e-01,
+         1.562355672780772e-01, -1.159947464637903e-01,
+        -4.178597956834860e-01,  2.350279359332042e-01,
+         1.386463518243497e-01,  2.759901192329840e-01,
+         6.061092567929459e-01,  3.421554570163159e-01,
+        -4.948342704648208e-01,  2.720124633017705e-02,
+         1.478071205500926e-01, -3.081735925986692e-01,
+         9.890855564926382e-02,  8.474773910644624e-02,
+        -5.556595541303113e-01,  2.400169887178655e-01,
+         3.287524237213155e-02,  5.794935589856006e-01,
+         1.652809399278383e-01,  4.255531662024501e-01,
+        -3.995783876577955e-02, -3.738480587747461e-01,
+        -2.794822703116759e-01, -1.843635505937004e-01,
+        -2.202358322179487e-01, -1.649028580929676e-01,
+         5.218038007259063e-01, -1.299420528901145e-01,
+        -1.877893990726392e-02,  1.951774856098924e-01,
+         3.943924627814211e-01,  3.422462568219887e-01,
+         5.290587888094611e-02, -3.771169342945497e-01,
+         5.028630541282803e-01,  3.932856363766604e-01,
+         1.818300848591654e-01, -1.896454270197960e-01,
+        -2.410293385364508e-01,  6.986778153453814e-02,
+        -2.502867652431296e-01, -1.114208492442469e-01,
+         1.292866619300987e-01, -9.693660556963215e-03,
+         3.942415587502210e-01,  2.621824089672617e-01,
+         2.871019484941495e-01, -4.479628465929596e-02,
+        -5.121958361976692e-02,  2.783382938836117e-02,
+         1.764874355680303e-01,  4.264844676461524e-01,
+         5.021968001039083e-01,  1.174243450673841e-01,
+         8.167337996688495e-02,  4.232450904519379e-02,
+    ];
+
+    {
+        let mut data = data.clone();
+        transform(&mut data, Operation::Forward, &dwt::wavelet::DiscreteMeyer::new(), 6);
+        assert::close(&data, &expected_data_6[..], 1e-14);
+    }
+}
+
+#[test]
+fn from_name() {
+    use dwt::wavelet::{Biorthogonal, Daubechies, Family, Haar, ReverseBiorthogonal, Wavelet};
+
+    let pairs: Vec<(&str, Wavelet<f64>)> = vec![
+        ("haar", Haar::new()),
+        ("db4", Daubechies::new(4)),
+        ("bior3.5", Biorthogonal::new(3, 5)),
+        ("rbio3.5", ReverseBiorthogonal::new(3, 5)),
+    ];
+    for (name, expected) in pairs {
+        let wavelet = Wavelet::<f64>::from_name(name).unwrap();
+        assert_eq!(wavelet.dec_lo.coefficients, expected.dec_lo.coefficients);
+        assert_eq!(wavelet.dec_lo.offset, expected.dec_lo.offset);
+        assert_eq!(wavelet.rec_hi.coefficients, expected.rec_hi.coefficients);
+        assert_eq!(wavelet.rec_hi.offset, expected.rec_hi.offset);
+    }
+
+    for family in Family::all() {
+        for name in family.members() {
+            assert!(Wavelet::<f64>::from_name(&name).is_ok());
+        }
+    }
+    assert_eq!(Family::Daubechies.members().len(), 38);
+    assert_eq!(Family::Symlets.members().len(), 19);
+    assert_eq!(Family::Coiflets.members().len(), 17);
+    assert_eq!(Family::Biorthogonal.members().len(), 15);
+
+    for name in ["db0", "db39", "sym1", "bior2.3", "db", "foo"].iter() {
+        match Wavelet::<f64>::from_name(name) {
+            Err(dwt::Error::UnknownWavelet(ref other)) => assert_eq!(other, name),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn orthogonal() {
+    use dwt::wavelet::{Coiflets, Daubechies, Haar, Wavelet};
+
+    for expected in [Daubechies::new(4), Coiflets::new(2)].iter() {
+        let wavelet = Wavelet::orthogonal(expected.dec_lo.coefficients.clone());
+        assert::close(&wavelet.dec_hi.coefficients, &expected.dec_hi.coefficients, 1e-15);
+        assert::close(&wavelet.rec_lo.coefficients, &expected.rec_lo.coefficients, 1e-15);
+        assert::close(&wavelet.rec_hi.coefficients, &expected.rec_hi.coefficients, 1e-15);
+    }
+
+    let data = (0..256).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+    let wavelet = Wavelet::orthogonal(Coiflets::new(2).dec_lo.coefficients);
+    let mut other = data.clone();
+    transform(&mut other, Operation::Forward, &wavelet, 4);
+    transform(&mut other, Operation::Inverse, &wavelet, 4);
+    assert::close(&other, &data, 1e-12);
+
+    let value = std::f64::consts::FRAC_1_SQRT_2;
+    let expected = Daubechies::new(2);
+    let mut padded = vec![0.0, 0.0];
+    padded.extend(&expected.dec_lo.coefficients);
+    padded.extend(&[0.0, 0.0]);
+    for (wavelet, expected) in [
+        (Wavelet::orthogonal(vec![value, value, 0.0, 0.0]), Haar::new()),
+        (Wavelet::orthogonal(padded), expected),
+    ].iter() {
+        assert!(wavelet.is_well_formed());
+        let mut other = data.clone();
+        transform(&mut other, Operation::Forward, wavelet, 4);
+        let mut expected_data = data.clone();
+        transform(&mut expected_data, Operation::Forward, expected, 4);
+        assert::close(&other, &expected_data, 1e-12);
+        transform(&mut other, Operation::Inverse, wavelet, 4);
+        assert::close(&other, &data, 1e-12);
+    }
+}
+
+#[test]
+#[should_panic(expected = "fewer than two nonzero coefficients")]
+fn orthogonal_one_tap() {
+    use dwt::wavelet::Wavelet;
+
+    Wavelet::orthogonal(vec![0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn validate() {
+    use dwt::wavelet::{Biorthogonal, Daubechies, DiscreteMeyer, Filter, Wavelet};
+
+    let validation = Daubechies::new::<f64>(4).validate();
+    assert!(validation.is_valid());
+    assert!(validation.orthogonal);
+    assert_eq!(validation.dec_vanishing_moments, 4);
+    assert_eq!(validation.rec_vanishing_moments, 4);
+
+    let validation = Biorthogonal::new::<f64>(3, 5).validate();
+    assert!(validation.is_valid());
+    assert!(!validation.orthogonal && validation.biorthogonal);
+    assert_eq!(validation.dec_vanishing_moments, 3);
+    assert_eq!(validation.rec_vanishing_moments, 5);
+
+    assert!(!DiscreteMeyer::new::<f64>().validate().is_valid());
+
+    let mut wavelet = Daubechies::new::<f64>(2);
+    wavelet.rec_lo.coefficients[0] += 1e-3;
+    let validation = wavelet.validate();
+    assert!(validation.well_formed && !validation.perfect_reconstruction);
+
+    let wavelet = Wavelet {
+        dec_lo: Filter { coefficients: vec![1.0], offset: 1 },
+        dec_hi: Filter { coefficients: vec![1.0], offset: 0 },
+        rec_lo: Filter { coefficients: vec![], offset: 0 },
+        rec_hi: Filter { coefficients: vec![1.0], offset: 0 },
+        properties: None,
+    };
+    assert!(!wavelet.is_well_formed());
+    assert!(!wavelet.validate().is_valid());
+}
+
+#[test]
+#[should_panic]
+fn transform_malformed() {
+    use dwt::wavelet::{Filter, Wavelet};
+
+    let wavelet = Wavelet {
+        dec_lo: Filter { coefficients: vec![1.0, 1.0], offset: 4 },
+        dec_hi: Filter { coefficients: vec![1.0, -1.0], offset: 0 },
+        rec_lo: Filter { coefficients: vec![1.0, 1.0], offset: 0 },
+        rec_hi: Filter { coefficients: vec![1.0, -1.0], offset: 0 },
+        properties: None,
+    };
+    let mut data = vec![0.0; 4];
+    transform(&mut data, Operation::Forward, &wavelet, 1);
+}
+
+#[test]
+fn properties() {
+    use dwt::wavelet::{Coiflets, Family, Symmetry, Wavelet};
+
+    let wavelet = Coiflets::new::<f64>(3);
+    let properties = wavelet.properties.as_ref().unwrap();
+    assert_eq!(properties.family, Family::Coiflets);
+    assert_eq!(properties.name, "coif3");
+    assert_eq!(properties.vanishing_moments_psi, 6);
+    assert_eq!(properties.vanishing_moments_phi, 5);
+    assert_eq!(properties.symmetry, Symmetry::NearSymmetric);
+    assert!(properties.orthogonal && properties.biorthogonal);
+    assert_eq!(properties.support_width, 17);
+    assert_eq!(properties.filter_length, 18);
+
+    for family in Family::all() {
+        for name in family.members() {
+            let wavelet = Wavelet::<f64>::from_name(&name).unwrap();
+            let properties = wavelet.properties.as_ref().unwrap();
+            assert_eq!(properties.family, *family);
+            assert_eq!(properties.name, name);
+            if *family != Family::DiscreteMeyer {
+                let validation = wavelet.validate();
+                assert_eq!(properties.vanishing_moments_psi, validation.dec_vanishing_moments);
+                assert!(!properties.orthogonal || validation.orthogonal);
+            }
+        }
+    }
+
+    let haar = Wavelet::<f64>::from_name("haar").unwrap().properties.unwrap();
+    let mut db1 = Wavelet::<f64>::from_name("db1").unwrap().properties.unwrap();
+    assert_eq!(haar.symmetry, Symmetry::Asymmetric);
+    db1.family = haar.family;
+    db1.name = haar.name.clone();
+    assert_eq!(db1, haar);
+    let length = |name| Wavelet::<f64>::from_name(name).unwrap().properties.unwrap().filter_length;
+    assert_eq!(length("dmey"), 61);
+    assert_eq!(length("bior2.2"), 5);
+}
+
+#[test]
+fn decompose_symmetric_daubechies_40() {
+    use dwt::Mode;
+    use dwt::wavelet::Daubechies;
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_data_3 = [
+         6.144019347686590e-01,  5.934836179506556e-01,
+         5.579438585734903e-01,  6.042679952768079e-01,
+         4.441863667109059e-01,  1.194247581497247e+00,
+         1.250406667044359e+00,  1.291911071019724e+00,
+         1.150768838679137e+00,  1.156888045259923e+00,
+         1.101030970043660e+00, -4.476389866394068e-02,
+        -1.497043063642173e-01, -9.242167502272072e-02,
+         3.379545980256300e-01, -5.138451512941483e-01,
+         3.682175255544676e-02,  5.170842470343091e-01,
+        -1.819169535993641e-01,  7.357066582466179e-02,
+        -5.517516422237135e-01,  3.209125141534678e-01,
+         7.405251503838031e-02,  2.263782710018591e-01,
+        -1.021716622123670e-01, -1.786634388056895e-03,
+        -4.446350727830962e-01, -1.391668239932203e-02,
+        -2.107879162398054e-01,  5.845883704483381e-01,
+         5.193548154667965e-01,  1.031636251476602e-02,
+        -3.097716943974859e-01, -1.519576989388269e-01,
+         2.976712398929976e-01,  2.163170610655766e-01,
+        -2.554765033181070e-01,  5.419694304823718e-02,
+         1.522222465925069e-01,  4.255834016518051e-03,
+         1.540229321359009e-01,  4.285966378972858e-01,
+        -3.708130135252071e-01, -8.983681049742338e-02,
+        -2.729288678108318e-01,  5.449518884960965e-01,
+        -1.889819027645715e-01, -1.336483258741391e-01,
+         4.291061700705719e-01, -2.203397650215761e-01,
+         2.351841868781072e-01,  3.416053671591714e-01,
+         2.435846219753526e-02,  2.017356038282745e-01,
+        -9.691321824070816e-02,  1.710205588428726e-01,
+         3.599352258452607e-01, -1.259335903090117e-01,
+        -3.465271170092171e-01, -4.926870269026042e-02,
+    ];
+
+    let coefficients = dwt::decompose(&data, &Daubechies::new(4), 3, Mode::Symmetric);
+    assert::close(&coefficients, &expected_data_3[..], 1e-14);
+}
+
+#[test]
+fn decompose_padding_daubechies_40() {
+    use dwt::Mode;
+    use dwt::wavelet::Daubechies;
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_zero_2 = [
+         8.283479893456815e-03, -5.302098324705853e-02,
+         2.016997672688389e-01,  2.907738712490059e-01,
+         9.131815175486960e-01,  1.186197046359789e+00,
+         9.756969620006015e-01,  5.284252241811885e-01,
+         1.203004744223153e+00,  6.885670426182128e-01,
+         6.501024952595216e-01,  1.212579833396110e+00,
+         5.684413580908714e-01, -7.822764443807609e-02,
+         9.275854848763540e-02,  5.623692214805238e-02,
+         5.710443944255768e-01,  2.152100647505757e-01,
+         1.063282865869609e-01, -3.097161425343088e-01,
+        -6.723818348414252e-01, -1.432971405304447e-01,
+         4.627236518217238e-01,  2.437389141780500e-02,
+        -1.855050539020257e-01,  6.019192568277239e-02,
+         2.989436094768458e-01,  6.337784410403884e-02,
+         1.104631320644163e-04,  2.039802254920481e-01,
+         3.619007184771463e-01, -3.041075635819453e-01,
+        -2.056636673059501e-01, -1.394684557542095e-01,
+         4.877304544711586e-01, -2.014172808060642e-01,
+        -1.236712166458081e-01,  4.113655009068775e-01,
+        -1.079742358717425e-01,  8.168399766255123e-02,
+         4.887527774109542e-01, -1.010116617482143e-01,
+         2.270635849729262e-01, -3.553443386478014e-02,
+         1.333623374483528e-01,  3.892482175950062e-01,
+        -1.428507737906762e-01,  4.237732337796071e-02,
+    ];
+
+    let expected_constant_2 = [
+         7.894791654462781e-01,  7.902275476530426e-01,
+         7.669131659124105e-01,  2.907738712490059e-01,
+         9.131815175486960e-01,  1.186197046359789e+00,
+         9.756969620006015e-01,  5.284252241811885e-01,
+         1.203004744223153e+00,  6.885670426182128e-01,
+         6.527413249696586e-01,  1.178507496681954e+00,
+         7.313299632489330e-01, -1.979139101730709e-02,
+        -1.300722405015673e-01,  1.160870410773341e-01,
+         5.710443944255768e-01,  2.152100647505757e-01,
+         1.063282865869609e-01, -3.097161425343088e-01,
+        -6.723818348414252e-01, -1.432971405304447e-01,
+         4.627236518217238e-01, -5.467258773844502e-04,
+         6.305277454994361e-03,  9.737964414136480e-03,
+         1.083978194374631e-01,  1.118630414428060e-01,
+         1.104631320644163e-04,  2.039802254920481e-01,
+         3.619007184771463e-01, -3.041075635819453e-01,
+        -2.056636673059501e-01, -1.394684557542095e-01,
+         4.877304544711586e-01, -2.014172808060642e-01,
+        -1.236712166458081e-01,  4.113655009068775e-01,
+        -1.079742358717425e-01,  8.168399766255123e-02,
+         4.887527774109542e-01, -1.010116617482143e-01,
+         2.270635849729262e-01, -3.553443386478014e-02,
+         1.333623374483528e-01,  3.892482175950062e-01,
+        -5.324434585273392e-02,  1.957658167437756e-02,
+    ];
+
+    let coefficients = dwt::decompose(&data, &Daubechies::new(3), 2, Mode::Zero);
+    assert::close(&coefficients, &expected_zero_2[..], 1e-14);
+    let coefficients = dwt::decompose(&data, &Daubechies::new(3), 2, Mode::Constant);
+    assert::close(&coefficients, &expected_constant_2[..], 1e-14);
+}
+
+#[test]
+fn decompose_extrapolation_daubechies_40() {
+    use dwt::Mode;
+    use dwt::wavelet::Daubechies;
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_smooth_2 = [
+         7.103272515272069e+00,  4.471705571151349e+00,
+         1.645693949300234e+00,  2.907738712490059e-01,
+         9.131815175486960e-01,  1.186197046359789e+00,
+         9.756969620006015e-01,  5.284252241811885e-01,
+         1.203004744223153e+00,  6.885670426182128e-01,
+         6.505723995717781e-01,  1.194888856054825e+00,
+         6.650838466190799e-01, -3.340955887615245e-52,
+        -2.255225939091193e-01,  2.091406284918383e-01,
+         5.710443944255768e-01,  2.152100647505757e-01,
+         1.063282865869609e-01, -3.097161425343088e-01,
+        -6.723818348414252e-01, -1.432971405304447e-01,
+         4.627236518217238e-01,  1.993620243540493e-02,
+        -4.157756095315351e-02, -1.085810663474954e-51,
+         8.352389719038111e-53,  1.626597052396868e-01,
+         1.104631320644163e-04,  2.039802254920481e-01,
+         3.619007184771463e-01, -3.041075635819453e-01,
+        -2.056636673059501e-01, -1.394684557542095e-01,
+         4.877304544711586e-01, -2.014172808060642e-01,
+        -1.236712166458081e-01,  4.113655009068775e-01,
+        -1.079742358717425e-01,  8.168399766255123e-02,
+         4.887527774109542e-01, -1.010116617482143e-01,
+         2.270635849729262e-01, -3.553443386478014e-02,
+         1.333623374483528e-01,  3.892482175950062e-01,
+        -1.319092545069221e-01, -1.294620406450907e-51,
+    ];
+
+    let expected_antisymmetric_2 = [
+        -2.238675563433357e-01,  1.579957552503321e-01,
+        -1.519218459397666e-01,  2.907738712490059e-01,
+         9.131815175486960e-01,  1.186197046359789e+00,
+         9.756969620006015e-01,  5.284252241811885e-01,
+         1.203004744223153e+00,  6.885670426182128e-01,
+         6.516582091520503e-01,  1.215515490666161e+00,
+         5.133860833440326e-01, -3.060588639132397e-01,
+         3.103797554824894e-01,  1.879213464426943e-02,
+         5.710443944255768e-01,  2.152100647505757e-01,
+         1.063282865869609e-01, -3.097161425343088e-01,
+        -6.723818348414252e-01, -1.432971405304447e-01,
+         4.627236518217238e-01,  9.682018582060126e-03,
+        -2.898478355818950e-01,  1.958082777537065e-01,
+         4.691396143237777e-01,  2.637082898914728e-02,
+         1.104631320644163e-04,  2.039802254920481e-01,
+         3.619007184771463e-01, -3.041075635819453e-01,
+        -2.056636673059501e-01, -1.394684557542095e-01,
+         4.877304544711586e-01, -2.014172808060642e-01,
+        -1.236712166458081e-01,  4.113655009068775e-01,
+        -1.079742358717425e-01,  8.168399766255123e-02,
+         4.887527774109542e-01, -1.010116617482143e-01,
+         2.270635849729262e-01, -3.553443386478014e-02,
+         1.333623374483528e-01,  3.892482175950062e-01,
+        -4.757960315487746e-02,  3.532308092448038e-01,
+    ];
+
+    let expected_antireflect_2 = [
+         2.252991955809130e+00,  1.432916624640687e+00,
+         1.131518528084898e+00,  2.907738712490059e-01,
+         9.131815175486960e-01,  1.186197046359789e+00,
+         9.756969620006015e-01,  5.284252241811885e-01,
+         1.203004744223153e+00,  6.885670426182128e-01,
+         6.412678528908146e-01,  1.267759681271513e+00,
+         3.911084979766610e-01,  6.513033388388183e-04,
+        -2.633604747624691e-01,  1.546948913877584e-01,
+         5.710443944255768e-01,  2.152100647505757e-01,
+         1.063282865869609e-01, -3.097161425343088e-01,
+        -6.723818348414252e-01, -1.432971405304447e-01,
+         4.627236518217238e-01,  1.078066118258898e-01,
+        -2.715067416494560e-01, -2.764103160416558e-01,
+        -3.183618989089636e-02,  1.454061914177266e-01,
+         1.104631320644163e-04,  2.039802254920481e-01,
+         3.619007184771463e-01, -3.041075635819453e-01,
+        -2.056636673059501e-01, -1.394684557542095e-01,
+         4.877304544711586e-01, -2.014172808060642e-01,
+        -1.236712166458081e-01,  4.113655009068775e-01,
+        -1.079742358717425e-01,  8.168399766255123e-02,
+         4.887527774109542e-01, -1.010116617482143e-01,
+         2.270635849729262e-01, -3.553443386478014e-02,
+         1.333623374483528e-01,  3.892482175950062e-01,
+        -4.659470147358200e-01, -5.777894459001677e-02,
+    ];
+
+    let coefficients = dwt::decompose(&data, &Daubechies::new(3), 2, Mode::Smooth);
+    assert::close(&coefficients, &expected_smooth_2[..], 1e-14);
+    let coefficients = dwt::decompose(&data, &Daubechies::new(3), 2, Mode::Antisymmetric);
+    assert::close(&coefficients, &expected_antisymmetric_2[..], 1e-14);
+    let coefficients = dwt::decompose(&data, &Daubechies::new(3), 2, Mode::AntiReflect);
+    assert::close(&coefficients, &expected_antireflect_2[..], 1e-14);
+
+    let data = (0..32).map(|i| 1.0 + 0.5 * i as f64).collect::<Vec<_>>();
+    let coefficients = dwt::decompose(&data, &Daubechies::new(2), 1, Mode::Smooth);
+    assert::close(&coefficients[17..], &[0.0; 17][..], 1e-12);
+}
+
+#[test]
+fn decompose_periodization_daubechies_37() {
+    use dwt::Mode;
+    use dwt::wavelet::Daubechies;
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_data_3 = [
+         1.213757233592202e+00,  1.123250597355468e+00,
+         1.339645760406880e+00,  1.296772739841857e+00,
+         1.079361388197328e+00, -6.508281489902742e-01,
+         1.141073770709602e-01, -5.412444092829368e-01,
+        -6.436581265962223e-02,  2.988499408103136e-01,
+        -1.291204114180143e-01, -2.263661061470541e-01,
+         2.010931561928777e-01, -8.136944564208035e-02,
+         4.599462704216128e-02,  2.805888855139049e-01,
+        -5.832390946781726e-01, -5.600188370562208e-01,
+         2.563152138029987e-01,  5.388717443950804e-02,
+        -2.131741947678791e-01, -4.926888959443713e-02,
+        -5.798076690973400e-02, -5.190216583192780e-01,
+         3.444966989670910e-01,  1.387322317241578e-02,
+         4.355655305187177e-01, -4.830498343972886e-01,
+         2.001555168107186e-02,  1.866856000932654e-01,
+        -4.184674550019095e-01,  2.937842486308274e-01,
+        -3.160458223826755e-01, -1.615438833119076e-01,
+        -2.376613645238256e-01, -1.837726587710705e-01,
+         1.318962556881191e-01, -1.268601062173403e-01,
+         8.256096765465924e-02,
+    ];
+
+    let coefficients = dwt::decompose(&data[..37], &Daubechies::new(2), 3, Mode::Periodization);
+    assert::close(&coefficients, &expected_data_3[..], 1e-14);
+}
+
+#[test]
+fn identity_extension() {
+    use dwt::Mode;
+    use dwt::wavelet::{Biorthogonal, Cdf97, Coiflets, Daubechies, Haar, Normalization};
+
+    let wavelets = [
+        Haar::new(), Daubechies::new(4), Coiflets::new(2), Biorthogonal::new(3, 5),
+        Cdf97::new(Normalization::Standard),
+    ];
+    let modes = [
+        Mode::Periodization, Mode::Symmetric, Mode::Zero, Mode::Constant, Mode::Smooth,
+        Mode::Antisymmetric, Mode::AntiReflect,
+    ];
+    for &length in [1, 5, 37, 40, 1000].iter() {
+        let data = (0..length).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+        for wavelet in wavelets.iter() {
+            for &mode in modes.iter() {
+                let coefficients = dwt::decompose(&data, wavelet, 3, mode);
+                let other = dwt::reconstruct(&coefficients, wavelet, 3, mode, length);
+                assert::close(&other, &data, 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn forward_whole_sample_symmetric_cdf97_40() {
+    use dwt::{Mode, Transform};
+    use dwt::wavelet::{Cdf97, Normalization};
+
+    let data = vec![
+        4.018080337519417e-01, 7.596669169084191e-02, 2.399161535536580e-01, 1.233189348351655e-01,
+        1.839077882824167e-01, 2.399525256649028e-01, 4.172670690843695e-01, 4.965443032574213e-02,
+        9.027161099152811e-01, 9.447871897216460e-01, 4.908640924680799e-01, 4.892526384000189e-01,
+        3.377194098213772e-01, 9.000538464176620e-01, 3.692467811202150e-01, 1.112027552937874e-01,
+        7.802520683211379e-01, 3.897388369612534e-01, 2.416912859138327e-01, 4.039121455881147e-01,
+        9.645452516838859e-02, 1.319732926063351e-01, 9.420505907754851e-01, 9.561345402298023e-01,
+        5.752085950784656e-01, 5.977954294715582e-02, 2.347799133724063e-01, 3.531585712220711e-01,
+        8.211940401979591e-01, 1.540343765155505e-02, 4.302380165780784e-02, 1.689900294627044e-01,
+        6.491154749564521e-01, 7.317223856586703e-01, 6.477459631363067e-01, 4.509237064309449e-01,
+        5.470088922863450e-01, 2.963208056077732e-01, 7.446928070741562e-01, 1.889550150325445e-01,
+    ];
+
+    let expected_data_2 = [
+         2.407207822164927e-01,  1.525588611443680e-01,
+         6.234453140958183e-01,  4.862649982726260e-01,
+         4.833254474432636e-01,  3.111313292242283e-01,
+         5.534793927132489e-01,  2.946483759130614e-01,
+         4.572612487904262e-01,  5.064379489875670e-01,
+        -1.581328306920363e-02, -2.474637719320198e-01,
+        -7.007410677489825e-02, -1.552016744314635e-01,
+        -1.026753147421079e-02,  5.887264300976726e-01,
+        -4.838869273255041e-01, -7.072035566475693e-01,
+         1.464576768304645e-01,  6.992557003925864e-02,
+        -2.675083565943431e-01, -5.650445879310737e-02,
+         6.446800621139506e-03, -7.316862508051882e-01,
+         2.674336732947220e-01,  6.557689130443299e-02,
+         6.670939368964403e-01, -5.770008421196795e-01,
+        -1.567896664262770e-01,  3.776375786677614e-01,
+        -4.705790307632194e-01,  1.882589491837380e-01,
+        -3.266708353808020e-01, -1.784641640478808e-01,
+        -4.431830143776231e-01, -1.297233269524173e-01,
+         6.731791117547366e-02, -1.355475141644881e-01,
+        -3.430537838975473e-01, -6.041801232976518e-01,
+    ];
+
+    {
+        let mut data = data.clone();
+        let wavelet = Cdf97::new(Normalization::Standard);
+        data.transform_with_mode(Operation::Forward, &wavelet, 2, Mode::WholeSampleSymmetric);
+        assert::close(&data, &expected_data_2[..], 1e-14);
+    }
+}
+
+#[test]
+fn identity_whole_sample_symmetric() {
+    use dwt::{Mode, Transform};
+    use dwt::wavelet::{Biorthogonal, Cdf97, LeGall53, Normalization};
+
+    let wavelets = [
+        Cdf97::new(Normalization::Standard), LeGall53::new(Normalization::Balanced),
+        Biorthogonal::new(2, 2), Biorthogonal::new(4, 4), Biorthogonal::new(6, 8),
+    ];
+    for &length in [5, 37, 48].iter() {
+        let data = (0..length).map(|i| (i as f64).sin() + (i as f64 / 7.0).cos()).collect::<Vec<_>>();
+        for wavelet in wavelets.iter() {
+            let mut other = data.clone();
+            other.transform_with_mode(Operation::Forward, wavelet, 3, Mode::WholeSampleSymmetric);
+            other.transform_with_mode(Operation::Inverse, wavelet, 3, Mode::WholeSampleSymmetric);
+            assert::close(&other, &data, 1e-12);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn whole_sample_symmetric_asymmetric() {
+    use dwt::{Mode, Transform};
+    use dwt::wavelet::Daubechies;
+
+    let mut data = [1.0; 16];
+    data.transform_with_mode(Operation::Forward, &Daubechies::new(4), 1, Mode::WholeSampleSymmetric);
+}
+
+#[test]
+fn try_transform() {
+    use dwt::{Error, Mode, Transform};
+    use dwt::wavelet::{Daubechies, Filter, Haar, Wavelet};
+
+    let wavelet = Haar::new();
+    let mut data = vec![1.0; 12];
+    assert_eq!(dwt::try_transform(&mut data, Operation::Forward, &wavelet, 3),
+               Err(Error::InvalidLength(12)));
+    assert_eq!(dwt::try_transform(&mut data, Operation::Forward, &wavelet, 4),
+               Err(Error::LevelTooDeep(4)));
+    assert_eq!(data.try_transform_with_mode(Operation::Forward, &wavelet, 1, Mode::Zero),
+               Err(Error::UnsupportedMode(Mode::Zero)));
+    assert_eq!(data.try_transform_with_mode(Operation::Forward, &Daubechies::new(2), 1,
+                                            Mode::WholeSampleSymmetric),
+               Err(Error::InvalidWavelet));
+    assert_eq!(data, vec![1.0; 12]);
+    assert_eq!(dwt::try_transform(&mut data, Operation::Forward, &wavelet, 2), Ok(()));
+
+    let wavelet = Wavelet {
+        dec_lo: Filter { coefficients: vec![1.0, 1.0], offset: 2 },
+        dec_hi: Filter { coefficients: vec![1.0, -1.0], offset: 0 },
+        rec_lo: Filter { coefficients: vec![1.0, 1.0], offset: 0 },
+        rec_hi: Filter { coefficients: vec![1.0, -1.0], offset: 0 },
+        properties: None,
+    };
+    assert_eq!(dwt::try_transform(&mut data, Operation::Forward, &wavelet, 1),
+               Err(Error::InvalidWavelet));
+
+    let wavelet = Daubechies::new(2);
+    assert_eq!(dwt::try_decompose(&[], &wavelet, 1, Mode::Symmetric), Err(Error::InvalidLength(0)));
+    let coefficients = dwt::decompose(&[1.0; 10], &wavelet, 2, Mode::Symmetric);
+    let error = dwt::try_reconstruct(&coefficients[1..], &wavelet, 2, Mode::Symmetric, 10);
+    assert_eq!(error, Err(Error::InvalidBufferSize { expected: 14, found: 13 }));
+    assert_eq!(error.unwrap_err().to_string(), "expected a buffer of size 14, found 13");
+
+    for &mode in &[Mode::Periodization, Mode::Symmetric] {
+        assert_eq!(dwt::try_decompose(&[1.0; 10], &wavelet, usize::MAX, mode),
+                   Err(Error::LevelTooDeep(usize::MAX)));
+        assert_eq!(dwt::try_wavedec(&[1.0; 10], &wavelet, usize::MAX, mode).err(),
+                   Some(Error::LevelTooDeep(usize::MAX)));
+        assert_eq!(dwt::try_reconstruct(&coefficients, &wavelet, 1 << 40, mode, 10),
+                   Err(Error::LevelTooDeep(1 << 40)));
+    }
+}
+
+#[test]
+fn max_level() {
+    use dwt::Transform;
+    use dwt::wavelet::{Daubechies, Wavelet};
+
+    let cases = [
+        ("haar", 9), ("db4", 7), ("db10", 5), ("sym6", 6), ("coif2", 6), ("bior3.5", 6),
+        ("rbio1.3", 7), ("dmey", 4), ("cdf97", 6), ("legall53", 7),
+    ];
+    for &(name, level) in &cases {
+        assert_eq!(dwt::max_level(1000, &Wavelet::<f64>::from_name(name).unwrap()), level, "{}", name);
+    }
+    let wavelet = Daubechies::new(4);
+    assert_eq!(dwt::max_level(6, &wavelet), 0);
+    assert_eq!(dwt::max_level(14, &wavelet), 1);
+
+    let data = (0..96).map(|i| (i as f64).sin()).collect::<Vec<_>>();
+    let mut other = data.clone();
+    assert_eq!(other.transform_at_max_level(Operation::Forward, &wavelet), 3);
+    let mut expected = data.clone();
+    expected.transform(Operation::Forward, &wavelet, 3);
+    assert_eq!(other, expected);
+    assert_eq!(other.transform_at_max_level(Operation::Inverse, &wavelet), 3);
+    assert::close(&other, &data, 1e-12);
+}
+
+#[test]
+fn wavedec() {
+    use dwt::Mode;
+    use dwt::wavelet::{Cdf97, Daubechies, Normalization};
+
+    let data = (0..37).map(|i| (i as f64 / 3.0).sin()).collect::<Vec<_>>();
+    let wavelet = Daubechies::new(4);
+    let decomposition = dwt::wavedec(&data, &wavelet, 3, Mode::Symmetric);
+    assert_eq!(decomposition.level(), 3);
+    assert_eq!(decomposition.signal_length(), 37);
+    assert_eq!(decomposition.coefficients(), &dwt::decompose(&data, &wavelet, 3, Mode::Symmetric)[..]);
+    assert_eq!(decomposition.approximation(), &decomposition.coefficients()[..10]);
+    assert_eq!(decomposition.detail(3), &decomposition.coefficients()[10..20]);
+    let details = decomposition.details().map(|(level, detail)| (level, detail.len()));
+    assert_eq!(details.collect::<Vec<_>>(), vec![(3, 10), (2, 14), (1, 22)]);
+    assert::close(dwt::waverec(&decomposition, &wavelet), &data, 1e-12);
+
+    let wavelet = Cdf97::new(Normalization::Standard);
+    let mut decomposition = dwt::wavedec(&data, &wavelet, 3, Mode::WholeSampleSymmetric);
+    assert_eq!(decomposition.approximation().len(), 5);
+    let details = decomposition.details().map(|(level, detail)| (level, detail.len()));
+    assert_eq!(details.collect::<Vec<_>>(), vec![(3, 5), (2, 9), (1, 18)]);
+    for value in decomposition.detail_mut(1) {
+        *value = 0.0;
+    }
+    let mut coefficients = data.clone();
+    dwt::Transform::transform_with_mode(&mut coefficients[..], Operation::Forward, &wavelet, 3,
+                                        Mode::WholeSampleSymmetric);
+    for value in &mut coefficients[19..] {
+        *value = 0.0;
+    }
+    assert_eq!(decomposition.coefficients(), &coefficients[..]);
+    assert::close(dwt::waverec(&decomposition, &wavelet),
+                  dwt::reconstruct(&coefficients, &wavelet, 3, Mode::WholeSampleSymmetric, 37),
+                  1e-12);
+}
+
+#[test]
+fn plan() {
+    use dwt::{Error, Mode, Plan, Transform};
+    use dwt::wavelet::{Daubechies, LeGall53, Normalization};
+
+    let wavelet = Daubechies::new(4);
+    let mut plan = Plan::new(256, wavelet.clone(), 5, Mode::Periodization).unwrap();
+    for k in 0..3 {
+        let data = (0..256).map(|i| ((i * (k + 1)) as f64 / 7.0).cos()).collect::<Vec<_>>();
+        let mut expected = data.clone();
+        expected.transform(Operation::Forward, &wavelet, 5);
+        let mut other = data.clone();
+        plan.forward(&mut other);
+        assert_eq!(other, expected);
+        plan.inverse(&mut other);
+        assert::close(&other, &data, 1e-12);
+    }
+
+    let mut plan = Plan::new(37, LeGall53::new(Normalization::Standard), 3,
+                             Mode::WholeSampleSymmetric).unwrap();
+    let data = (0..37).map(|i| i as f64).collect::<Vec<_>>();
+    let mut other = data.clone();
+    plan.execute(&mut other, Operation::Forward);
+    plan.execute(&mut other, Operation::Inverse);
+    assert::close(&other, &data, 1e-12);
+
+    assert!(Plan::<f64>::new(12, Daubechies::new(4), 3, Mode::Periodization).err() ==
+            Some(Error::InvalidLength(12)));
+}
+
+#[test]
+fn transform_with_workspace() {
+    use dwt::{Error, Mode, Transform};
+    use dwt::wavelet::{Cdf97, Daubechies, Normalization};
+
+    let data = (0..64).map(|i| (i as f64 / 5.0).sin()).collect::<Vec<_>>();
+    let wavelet = Daubechies::new(3);
+    assert_eq!(dwt::workspace_size(64, 4, Mode::Periodization), 64);
+    assert_eq!(dwt::workspace_size(64, 0, Mode::Periodization), 0);
+    let mut workspace = vec![0.0; 100];
+    let mut other = data.clone();
+    dwt::transform_with_workspace(&mut other, &mut workspace, Operation::Forward, &wavelet, 4);
+    let mut expected = data.clone();
+    expected.transform(Operation::Forward, &wavelet, 4);
+    assert_eq!(other, expected);
+    dwt::transform_with_workspace(&mut other, &mut workspace, Operation::Inverse, &wavelet, 4);
+    assert::close(&other, &data, 1e-12);
+
+    let wavelet = Cdf97::new(Normalization::Standard);
+    let mut other = data[..45].to_vec();
+    let size = dwt::workspace_size(45, 3, Mode::WholeSampleSymmetric);
+    assert_eq!(other.try_transform_with_workspace(&mut workspace[..(size - 1)], Operation::Forward,
+                                                  &wavelet, 3, Mode::WholeSampleSymmetric),
+               Err(Error::InvalidBufferSize { expected: 45, found: 44 }));
+    other.transform_with_workspace(&mut workspace[..size], Operation::Forward, &wavelet, 3,
+                                   Mode::WholeSampleSymmetric);
+    other.transform_with_workspace(&mut workspace[..size], Operation::Inverse, &wavelet, 3,
+                                   Mode::WholeSampleSymmetric);
+    assert::close(&other, &data[..45], 1e-12);
+}
+
+#[test]
+fn transform_into() {
+    use dwt::{Error, Mode, Transform};
+    use dwt::wavelet::{Coiflets, LeGall53, Normalization};
+
+    let data = (0..48).map(|i| (i as f64 / 4.0).cos()).collect::<Vec<_>>();
+    let wavelet = Coiflets::new(2);
+    let mut expected = data.clone();
+    expected.transform(Operation::Forward, &wavelet, 3);
+    let mut coefficients = vec![0.0; 48];
+    dwt::transform_into(&data, &mut coefficients, Operation::Forward, &wavelet, 3);
+    assert_eq!(coefficients, expected);
+    assert::close(dwt::transform_to_vec(&coefficients, Operation::Inverse, &wavelet, 3), &data,
+                  1e-12);
+
+    let wavelet = LeGall53::new(Normalization::Standard);
+    let mode = Mode::WholeSampleSymmetric;
+    let mut expected = data[..43].to_vec();
+    expected.transform_with_mode(Operation::Forward, &wavelet, 4, mode);
+    let mut coefficients = vec![0.0; 43];
+    dwt::transform_into_with_mode(&data[..43], &mut coefficients, Operation::Forward, &wavelet, 4,
+                                  mode);
+    assert::close(&coefficients, &expected, 1e-12);
+    let mut other = vec![0.0; 43];
+    dwt::try_transform_into_with_mode(&coefficients, &mut other, Operation::Inverse, &wavelet, 4,
+                                      mode).unwrap();
+    assert::close(&other, &data[..43], 1e-12);
+    assert_eq!(dwt::try_transform_into(&data, &mut other, Operation::Inverse, &wavelet, 4),
+               Err(Error::InvalidBufferSize { expected: 48, found: 43 }));
+}
+
+#[test]
+fn dwt() {
+    use dwt::{Error, Mode};
+    use dwt::wavelet::{Biorthogonal, Daubechies};
+
+    let data = (0..37).map(|i| (i as f64 / 6.0).sin() + 0.1 * i as f64).collect::<Vec<_>>();
+    let modes = [Mode::Periodization, Mode::Symmetric, Mode::Smooth, Mode::WholeSampleSymmetric];
+    for &mode in &modes {
+        let wavelet = match mode {
+            Mode::WholeSampleSymmetric => Biorthogonal::new(2, 2),
+            _ => Daubechies::new(3),
+        };
+        let (approximation, detail) = dwt::dwt(&data, &wavelet, mode);
+        let coefficients = dwt::decompose(&data, &wavelet, 1, mode);
+        assert_eq!([&approximation[..], &detail[..]].concat(), coefficients);
+        assert::close(dwt::idwt(Some(&approximation), Some(&detail), &wavelet, mode, 37), &data[..],
+                      1e-12);
+
+        let smooth = dwt::idwt(Some(&approximation), None, &wavelet, mode, 37);
+        let rough = dwt::idwt(None, Some(&detail), &wavelet, mode, 37);
+        let sum = smooth.iter().zip(&rough).map(|(&smooth, &rough)| smooth + rough);
+        assert::close(sum.collect::<Vec<_>>(), &data, 1e-12);
+        assert_eq!(dwt::try_idwt(Some(&detail[1..]), None, &wavelet, mode, 37),
+                   Err(Error::InvalidBufferSize { expected: approximation.len(),
+                                                  found: detail.len() - 1 }));
+    }
+}
+
+#[test]
+fn swt() {
+    use dwt::{Error, Transform};
+    use dwt::wavelet::{Biorthogonal, Daubechies};
+
+    let data = (0..64).map(|i| (i as f64 / 5.0).sin() + (i % 7) as f64 / 10.0).collect::<Vec<_>>();
+    let wavelet = Daubechies::new(4);
+    let levels = dwt::swt(&data, &wavelet, 3);
+    assert_eq!(levels.len(), 3);
+    for j in 1..4 {
+        let mut expected = data.clone();
+        expected.transform(Operation::Forward, &wavelet, j);
+        let (ref approximation, ref detail) = levels[3 - j];
+        let m = 64 >> j;
+        for i in 0..m {
+            assert::close(approximation[i << j], expected[i], 1e-12);
+            assert::close(detail[i << j], expected[m + i], 1e-12);
+        }
+    }
+    assert::close(dwt::iswt(&levels, &wavelet), &data[..], 1e-12);
+
+    let wavelet = Biorthogonal::new(3, 5);
+    assert::close(dwt::iswt(&dwt::swt(&data, &wavelet, 4), &wavelet), &data[..], 1e-12);
+
+    assert_eq!(dwt::try_swt(&data[..60], &wavelet, 3).err(), Some(Error::InvalidLength(60)));
+    let mut levels = dwt::swt(&data, &wavelet, 2);
+    levels[1].1.pop();
+    assert_eq!(dwt::try_iswt(&levels, &wavelet), Err(Error::InvalidBufferSize { expected: 64,
+                                                                                found: 63 }));
+}
+
+#[test]
+fn modwt() {
+    use dwt::Error;
+    use dwt::wavelet::{Biorthogonal, Daubechies, Haar};
+
+    let data = (0..50).map(|i| (i as f64 / 4.0).sin() + (i % 5) as f64 / 10.0).collect::<Vec<_>>();
+    let energy = |data: &[f64]| data.iter().fold(0.0, |sum, &value| sum + value * value);
+
+    let wavelet = Daubechies::new(3);
+    let levels = dwt::modwt(&data, &wavelet, 4);
+    let total = levels.iter().fold(energy(&levels[0].0), |sum, level| sum + energy(&level.1));
+    assert::close(total, energy(&data), 1e-10);
+    assert::close(dwt::imodwt(&levels, &wavelet), &data[..], 1e-12);
+
+    let stationary = dwt::swt(&data[..48], &wavelet, 3);
+    let levels = dwt::modwt(&data[..48], &wavelet, 3);
+    for j in 0..3 {
+        let scale = 2f64.powf((3 - j) as f64 / 2.0);
+        let expected = stationary[j].1.iter().map(|&value| value / scale).collect::<Vec<_>>();
+        assert::close(&levels[j].1, &expected, 1e-12);
+    }
+
+    let wavelet = Biorthogonal::new(2, 4);
+    assert::close(dwt::imodwt(&dwt::modwt(&data, &wavelet, 3), &wavelet), &data[..], 1e-12);
+    let components = dwt::mra(&data, &wavelet, 3);
+    assert_eq!(components.len(), 4);
+    let sum = (0..50).map(|i| components.iter().fold(0.0, |sum, component| sum + component[i]));
+    assert::close(sum.collect::<Vec<_>>(), &data[..], 1e-12);
+
+    let ramp = (0..40).map(|i| i as f64).collect::<Vec<_>>();
+    let components = dwt::mra(&ramp, &Daubechies::new(2), 2);
+    assert::close(&components[0][10..30], &[0.0; 20][..], 1e-12);
+
+    assert_eq!(dwt::try_modwt(&[], &wavelet, 1), Err(Error::InvalidLength(0)));
+
+    let wavelet = Daubechies::new(3);
+    for &level in &[63, 64, 65] {
+        assert_eq!(dwt::try_modwt(&data, &wavelet, level), Err(Error::LevelTooDeep(level)));
+        assert_eq!(dwt::try_mra(&data, &wavelet, level), Err(Error::LevelTooDeep(level)));
+        let levels = vec![(vec![0.0; 50], vec![0.0; 50]); level];
+        assert_eq!(dwt::try_imodwt(&levels, &wavelet), Err(Error::LevelTooDeep(level)));
+    }
+    assert_eq!(dwt::modwt(&data, &Haar::new(), 64).len(), 64);
+}
+
+#[test]
+fn wavelet_packet() {
+    use dwt::{Error, Mode, Order, Transform, WaveletPacket};
+    use dwt::wavelet::{Daubechies, LeGall53, Normalization};
+
+    let data = (0..64).map(|i| (i as f64 / 3.0).sin() + (i % 6) as f64 / 8.0).collect::<Vec<_>>();
+    let wavelet = Daubechies::new(2);
+    let packet = WaveletPacket::new(&data, &wavelet, 3, Mode::Periodization).unwrap();
+    assert_eq!(packet.node(""), Some(&data[..]));
+    let mut expected = data.clone();
+    expected.transform(Operation::Forward, &wavelet, 3);
+    assert_eq!(packet.node("aaa"), Some(&expected[..8]));
+    assert_eq!(packet.node("aad"), Some(&expected[8..16]));
+    assert_eq!(packet.node("ad"), Some(&expected[16..32]));
+    assert_eq!(packet.node("aaaa"), None);
+    assert_eq!(packet.node("ab"), None);
+    assert_eq!(packet.node_at(2, 2, Order::Natural), packet.node("da"));
+    assert_eq!(packet.node_at(2, 2, Order::Frequency), packet.node("dd"));
+
+    let paths = packet.nodes(2, Order::Frequency).into_iter().map(|(path, _)| path);
+    assert_eq!(paths.collect::<Vec<_>>(), vec!["aa", "ad", "dd", "da"]);
+    let paths = packet.nodes(3, Order::Frequency).into_iter().map(|(path, _)| path);
+    assert_eq!(paths.collect::<Vec<_>>(),
+               vec!["aaa", "aad", "add", "ada", "dda", "ddd", "dad", "daa"]);
+
+    for paths in &[&["aaa", "aad", "ad", "d"][..], &["aa", "ada", "add", "da", "dda", "ddd"][..]] {
+        assert::close(packet.reconstruct(&wavelet, paths).unwrap(), &data[..], 1e-12);
+    }
+    let smooth = packet.reconstruct(&wavelet, &["a"]).unwrap();
+    let rough = packet.reconstruct(&wavelet, &["da", "dd"]).unwrap();
+    let sum = smooth.iter().zip(&rough).map(|(&smooth, &rough)| smooth + rough);
+    assert::close(sum.collect::<Vec<_>>(), &data[..], 1e-12);
+    assert_eq!(packet.reconstruct(&wavelet, &["dx"]), Err(Error::InvalidPath("dx".to_string())));
+
+    let wavelet = LeGall53::new(Normalization::Standard);
+    let packet = WaveletPacket::new(&data[..45], &wavelet, 3, Mode::WholeSampleSymmetric).unwrap();
+    let paths = packet.nodes(3, Order::Natural).into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+    let paths = paths.iter().map(|path| &path[..]).collect::<Vec<_>>();
+    assert::close(packet.reconstruct(&wavelet, &paths).unwrap(), &data[..45], 1e-12);
+}
+
+#[test]
+fn reversible_legall53() {
+    use dwt::Error;
+    use dwt::wavelet::LeGall53;
+
+    let mut data = [1, 2, 3, 4, 5, 6, 7, 8];
+    LeGall53::transform_reversible(&mut data, Operation::Forward, 1);
+    assert_eq!(data, [1, 3, 5, 7, 0, 0, 0, 1]);
+    LeGall53::transform_reversible(&mut data, Operation::Inverse, 1);
+    assert_eq!(data, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let mut data = [-3i64, 5, 2, -7, 1];
+    LeGall53::transform_reversible(&mut data, Operation::Forward, 1);
+    assert_eq!(data, [0, 2, -3, 6, -8]);
+    LeGall53::transform_reversible(&mut data, Operation::Inverse, 1);
+    LeGall53::transform_reversible(&mut data, Operation::Forward, 2);
+    assert_eq!(data, [2, -1, 4, 6, -8]);
+    LeGall53::transform_reversible(&mut data, Operation::Inverse, 2);
+    assert_eq!(data, [-3, 5, 2, -7, 1]);
+
+    let data = (0..77).map(|i| (i * i * 37 % 255) - 128).collect::<Vec<i32>>();
+    let mut other = data.clone();
+    LeGall53::transform_reversible(&mut other, Operation::Forward, 4);
+    assert!(other != data);
+    LeGall53::transform_reversible(&mut other, Operation::Inverse, 4);
+    assert_eq!(other, data);
+
+    assert_eq!(LeGall53::try_transform_reversible(&mut [1, 2, 3], Operation::Forward, 3),
+               Err(Error::LevelTooDeep(3)));
+    assert_eq!(LeGall53::try_transform_reversible(&mut [0i32; 0], Operation::Forward, 1),
+               Err(Error::InvalidLength(0)));
+}